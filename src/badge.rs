@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Coverage badge in the flat shields.io style, rendered locally so that CI
+//! can commit or publish it without calling out to shields.io.
+
+use crate::ReportSummary;
+
+const LABEL: &str = "dependabot";
+
+pub fn render_badge(summary: &ReportSummary) -> String {
+    let (value, color) = if summary.total_ecosystems == 0 {
+        ("n/a".to_string(), "#9f9f9f")
+    } else {
+        (
+            format!(
+                "{}/{}",
+                summary.configured_ecosystems, summary.total_ecosystems
+            ),
+            color(summary.configured_ecosystems, summary.total_ecosystems),
+        )
+    };
+
+    let label_width = text_width(LABEL) + 10;
+    let value_width = text_width(&value) + 10;
+    let width = label_width + value_width;
+    let title = format!("{}: {}", LABEL, value);
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{title}">
+<title>{title}</title>
+<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>
+<clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+<g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="#555"/><rect x="{label_width}" width="{value_width}" height="20" fill="{color}"/><rect width="{width}" height="20" fill="url(#s)"/></g>
+<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+<text x="{label_x}" y="15" fill="#010101" fill-opacity=".3">{LABEL}</text><text x="{label_x}" y="14">{LABEL}</text>
+<text x="{value_x}" y="15" fill="#010101" fill-opacity=".3">{value}</text><text x="{value_x}" y="14">{value}</text>
+</g>
+</svg>
+"##,
+        label_x = label_width as f64 / 2.0,
+        value_x = label_width as f64 + value_width as f64 / 2.0,
+    )
+}
+
+/// Green only at full coverage, so that a single uncovered ecosystem shows.
+fn color(configured: usize, total: usize) -> &'static str {
+    let percent = configured * 100 / total;
+    match percent {
+        100.. => "#4c1",
+        80.. => "#dfb317",
+        50.. => "#fe7d37",
+        _ => "#e05d44",
+    }
+}
+
+/// Approximate width in pixels of `text` in 11px Verdana, which is close
+/// enough for the short labels and numbers of the badge.
+fn text_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '!' | '|' => 3,
+            'f' | 'r' | 't' | '/' | ' ' | '(' | ')' | '[' | ']' | '-' => 5,
+            'm' | 'w' | 'M' | 'W' | '%' => 10,
+            'A'..='Z' => 8,
+            _ => 7,
+        })
+        .sum()
+}
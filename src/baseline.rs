@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Findings accepted when the check was introduced, so that later runs only
+//! report new ones.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "entries": [
+//!     {
+//!       "kind": "missing-ecosystem",
+//!       "ecosystem": "npm",
+//!       "directory": "docs",
+//!       "expires": "2026-06-30",
+//!       "reason": "docs site is rebuilt from scratch every quarter"
+//!     }
+//!   ]
+//! }
+//! ```
+
+use crate::date;
+use crate::finding::{Finding, FindingKind, Suppression};
+use crate::{DependencyReport, Ecosystem, Error};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct Baseline {
+    pub version: u32,
+
+    #[serde(default)]
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// Suppresses the findings of a kind about an ecosystem in a directory, about
+/// an `updates` entry identified by its ecosystem, directories and target
+/// branch, or about a file for findings that concern no ecosystem.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct BaselineEntry {
+    pub kind: FindingKind,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecosystem: Option<Ecosystem>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directories: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+
+    /// Last day the entry applies, as `YYYY-MM-DD`. The finding fails the
+    /// check again afterwards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl BaselineEntry {
+    pub fn from_finding(finding: &Finding) -> Self {
+        BaselineEntry {
+            kind: finding.kind,
+            ecosystem: finding.ecosystem.clone(),
+            directory: finding.directory.clone(),
+            directories: finding.directories.clone(),
+            target_branch: finding.target_branch.clone(),
+            file: match finding.ecosystem {
+                Some(_) => None,
+                None => Some(finding.file.clone()),
+            },
+            expires: None,
+            reason: None,
+        }
+    }
+
+    pub fn matches(&self, finding: &Finding) -> bool {
+        self.kind == finding.kind
+            && self.ecosystem == finding.ecosystem
+            && self.directory == finding.directory
+            && self.directories == finding.directories
+            && self.target_branch == finding.target_branch
+            && self.file.as_ref().is_none_or(|file| *file == finding.file)
+    }
+
+    /// Whether the entry stopped applying before `today`, a `YYYY-MM-DD`
+    /// date.
+    pub fn is_expired(&self, today: &str) -> bool {
+        self.expires
+            .as_deref()
+            .is_some_and(|expires| expires < today)
+    }
+
+    fn same_target(&self, other: &BaselineEntry) -> bool {
+        self.kind == other.kind
+            && self.ecosystem == other.ecosystem
+            && self.directory == other.directory
+            && self.directories == other.directories
+            && self.target_branch == other.target_branch
+            && self.file == other.file
+    }
+}
+
+impl Baseline {
+    /// Records every finding of `report`, keeping the expiry dates and
+    /// reasons of the entries of `previous` that still match.
+    pub fn from_report(report: &DependencyReport, previous: Option<&Baseline>) -> Self {
+        let mut entries: Vec<BaselineEntry> = Vec::new();
+        for finding in report.all_findings() {
+            let entry = BaselineEntry::from_finding(&finding);
+            if entries.iter().any(|existing| existing.same_target(&entry)) {
+                continue;
+            }
+            let kept = previous
+                .and_then(|previous| {
+                    previous
+                        .entries
+                        .iter()
+                        .find(|existing| existing.same_target(&entry))
+                })
+                .cloned();
+            entries.push(kept.unwrap_or(entry));
+        }
+        entries.sort_by(|a, b| {
+            let key = |entry: &BaselineEntry| {
+                (
+                    entry.kind.as_str(),
+                    entry.ecosystem.clone(),
+                    entry.directory.clone(),
+                    entry.directories.clone(),
+                    entry.target_branch.clone(),
+                    entry.file.clone(),
+                )
+            };
+            key(a).cmp(&key(b))
+        });
+
+        Baseline {
+            version: VERSION,
+            entries,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, Error> {
+        let display = path.display().to_string();
+        let content = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: display.clone(),
+            source,
+        })?;
+        let parse_error = |message: String| Error::Parse {
+            path: display.clone(),
+            message,
+        };
+
+        let baseline: Baseline =
+            serde_json::from_str(&content).map_err(|err| parse_error(err.to_string()))?;
+        if baseline.version != VERSION {
+            return Err(parse_error(format!(
+                "unsupported baseline version {}, expected {}",
+                baseline.version, VERSION
+            )));
+        }
+        for (index, entry) in baseline.entries.iter().enumerate() {
+            if let Some(expires) = &entry.expires {
+                if !date::is_date(expires) {
+                    return Err(parse_error(format!(
+                        "entries[{}].expires: `{}` is not a YYYY-MM-DD date",
+                        index, expires
+                    )));
+                }
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(self).map_err(|err| Error::Serialize {
+            what: "baseline",
+            message: err.to_string(),
+        })?;
+        fs::write(path, format!("{}\n", json)).map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Suppresses the findings of `report` that have an entry, attributing
+    /// them to `source`. Returns the expired entries that matched a finding,
+    /// whose findings are reported again.
+    pub fn apply(&self, report: &mut DependencyReport, source: &str) -> Vec<BaselineEntry> {
+        let today = date::today();
+        let mut expired: Vec<BaselineEntry> = Vec::new();
+
+        report.suppress(|finding| {
+            let entry = self.entries.iter().find(|entry| entry.matches(finding))?;
+            if entry.is_expired(&today) {
+                if !expired.contains(entry) {
+                    expired.push(entry.clone());
+                }
+                return None;
+            }
+            Some(Suppression {
+                suppressed_by: source.to_string(),
+                justification: entry.reason.clone(),
+            })
+        });
+
+        expired
+    }
+}
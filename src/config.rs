@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Configuration of up2date itself, read from `.up2date.toml` in the root of
+//! the analyzed project.
+//!
+//! ```toml
+//! [[detect]]
+//! files = "**/BUILD.ourtool"
+//! content = 'go_repository\('
+//! ecosystem = "gomod"
+//! directory = "file"
+//! ```
+
+use crate::detect::{Detection, Detector, ProjectFile};
+use crate::{Ecosystem, Error};
+use globset::{GlobBuilder, GlobMatcher};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+pub const CONFIG_PATH: &str = ".up2date.toml";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ProjectConfig {
+    /// Rules detecting ecosystems the built-in detectors do not recognize.
+    #[serde(default, rename = "detect", skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<DetectRule>,
+}
+
+/// Files matching `files`, and `content` if set, are dependencies of
+/// `ecosystem`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct DetectRule {
+    /// Glob matched against paths relative to the project root, such as
+    /// `**/BUILD.ourtool`.
+    pub files: String,
+
+    /// Regular expression the content of the file has to match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// Any name, including ecosystems Dependabot does not know. No `updates`
+    /// entry can cover those, so they are reported as missing unless
+    /// suppressed.
+    pub ecosystem: Ecosystem,
+
+    #[serde(default)]
+    pub directory: DirectoryStrategy,
+}
+
+/// Which directory the update tool has to look in for a matched file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum DirectoryStrategy {
+    /// The directory of the file.
+    #[default]
+    File,
+    /// The parent of the directory of the file, for manifests kept in a
+    /// subdirectory such as `build/`.
+    Parent,
+    /// The project root, for files only the root configuration refers to.
+    Root,
+}
+
+impl ProjectConfig {
+    /// Reads `.up2date.toml` from `project_root`, if it exists.
+    pub fn load(project_root: &Path) -> Result<Option<ProjectConfig>, Error> {
+        let content = match fs::read_to_string(project_root.join(CONFIG_PATH)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: CONFIG_PATH.to_string(),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|err| parse_error(err.to_string().trim_end()))
+    }
+
+    /// Compiles the rules into detectors.
+    pub fn detectors(&self) -> Result<Vec<Arc<dyn Detector>>, Error> {
+        self.rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                RuleDetector::new(index, rule)
+                    .map(|detector| Arc::new(detector) as Arc<dyn Detector>)
+            })
+            .collect()
+    }
+}
+
+/// The built-in detectors and those of the `.up2date.toml` of
+/// `project_root`.
+pub(crate) fn project_detectors(project_root: &Path) -> Result<Vec<Arc<dyn Detector>>, Error> {
+    let mut detectors = crate::detect::builtin();
+    if let Some(config) = ProjectConfig::load(project_root)? {
+        detectors.extend(config.detectors()?);
+    }
+    Ok(detectors)
+}
+
+struct RuleDetector {
+    name: String,
+    files: GlobMatcher,
+    content: Option<Regex>,
+    ecosystem: Ecosystem,
+    directory: DirectoryStrategy,
+}
+
+impl RuleDetector {
+    fn new(index: usize, rule: &DetectRule) -> Result<Self, Error> {
+        let name = format!("detect[{}]", index);
+        let files = GlobBuilder::new(&rule.files)
+            .literal_separator(true)
+            .build()
+            .map_err(|err| parse_error(format!("{}.files: {}", name, err.kind())))?
+            .compile_matcher();
+        let content = rule
+            .content
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|err| parse_error(format!("{}.content: {}", name, err)))?;
+
+        Ok(RuleDetector {
+            name,
+            files,
+            content,
+            ecosystem: rule.ecosystem.clone(),
+            directory: rule.directory,
+        })
+    }
+}
+
+impl Detector for RuleDetector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection> {
+        if !self.files.is_match(file.path()) {
+            return Vec::new();
+        }
+        if let Some(content) = &self.content {
+            if !file.content().is_some_and(|text| content.is_match(text)) {
+                return Vec::new();
+            }
+        }
+
+        let detection = Detection::new(self.ecosystem.clone(), file);
+        let directory = match self.directory {
+            DirectoryStrategy::File => return vec![detection],
+            DirectoryStrategy::Parent => match Path::new(&detection.directory).parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => {
+                    parent.to_string_lossy().to_string()
+                }
+                _ => ".".to_string(),
+            },
+            DirectoryStrategy::Root => ".".to_string(),
+        };
+        vec![detection.with_directory(directory)]
+    }
+}
+
+fn parse_error(message: impl Into<String>) -> Error {
+    Error::Parse {
+        path: CONFIG_PATH.to_string(),
+        message: message.into(),
+    }
+}
@@ -0,0 +1,1241 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::dependabot::{
+    self, DependabotConfig, GroupConfig, IgnoreCondition, LegacyConfig, ScheduleConfig,
+    UpdateConfig,
+};
+use crate::renovate::{self, PackageRule, RenovateConfig};
+use crate::{Ecosystem, Error, ProjectDependency};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+const WEEKDAYS: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+/// A converted configuration together with everything that could not be
+/// carried over faithfully.
+#[derive(Debug)]
+pub struct Conversion<T> {
+    pub config: T,
+    pub warnings: Vec<String>,
+}
+
+/// Converts the Renovate configuration of `project_root` into a Dependabot
+/// configuration, rendered as YAML.
+pub fn convert_to_dependabot(project_root: &Path) -> Result<Conversion<String>, Error> {
+    let (path, config) = load_renovate_config(project_root)?;
+    let mut findings = Vec::new();
+    let dependencies = crate::find_project_dependencies(
+        project_root,
+        &GlobSet::empty(),
+        &crate::config::project_detectors(project_root)?,
+        &mut findings,
+    );
+
+    let mut conversion = renovate_to_dependabot(&config, &dependencies);
+    conversion
+        .warnings
+        .extend(findings.into_iter().map(|finding| finding.message));
+    for other in renovate::find_configs(project_root).into_iter().skip(1) {
+        conversion.warnings.push(format!(
+            "`{}` was not converted, Renovate only reads the first configuration it finds",
+            other
+        ));
+    }
+    render_dependabot(path, conversion)
+}
+
+/// Converts the Dependabot Preview configuration of `project_root` into a
+/// version 2 Dependabot configuration, rendered as YAML.
+pub fn convert_legacy_to_dependabot(project_root: &Path) -> Result<Conversion<String>, Error> {
+    let config = load_legacy_config(project_root)?;
+
+    render_dependabot(
+        dependabot::LEGACY_CONFIG_PATH,
+        legacy_to_dependabot(&config),
+    )
+}
+
+/// Converts the Dependabot configuration of `project_root` into a Renovate
+/// configuration, rendered as JSON.
+pub fn convert_to_renovate(project_root: &Path) -> Result<Conversion<String>, Error> {
+    let (path, config) = load_dependabot_config(project_root)?;
+
+    render_renovate(path, dependabot_to_renovate(&config))
+}
+
+/// Converts the Dependabot Preview configuration of `project_root` into a
+/// Renovate configuration, rendered as JSON.
+pub fn convert_legacy_to_renovate(project_root: &Path) -> Result<Conversion<String>, Error> {
+    let config = load_legacy_config(project_root)?;
+
+    let legacy = legacy_to_dependabot(&config);
+    let mut conversion = dependabot_to_renovate(&legacy.config);
+    conversion.warnings.splice(0..0, legacy.warnings);
+
+    render_renovate(dependabot::LEGACY_CONFIG_PATH, conversion)
+}
+
+fn render_dependabot(
+    path: &str,
+    conversion: Conversion<DependabotConfig>,
+) -> Result<Conversion<String>, Error> {
+    let yaml = serde_yaml::to_string(&conversion.config).map_err(|err| Error::Serialize {
+        what: "Dependabot",
+        message: err.to_string(),
+    })?;
+
+    Ok(Conversion {
+        config: yaml,
+        warnings: prefix_warnings(path, conversion.warnings),
+    })
+}
+
+fn render_renovate(
+    path: &str,
+    conversion: Conversion<RenovateConfig>,
+) -> Result<Conversion<String>, Error> {
+    let json =
+        serde_json::to_string_pretty(&conversion.config).map_err(|err| Error::Serialize {
+            what: "Renovate",
+            message: err.to_string(),
+        })?;
+
+    Ok(Conversion {
+        config: json,
+        warnings: prefix_warnings(path, conversion.warnings),
+    })
+}
+
+fn prefix_warnings(path: &str, warnings: Vec<String>) -> Vec<String> {
+    warnings
+        .into_iter()
+        .map(|warning| format!("{}: {}", path, warning))
+        .collect()
+}
+
+pub(crate) fn load_renovate_config(
+    project_root: &Path,
+) -> Result<(&'static str, RenovateConfig), Error> {
+    let Some(path) = renovate::find_config(project_root) else {
+        return Err(Error::NoConfig("Renovate"));
+    };
+
+    if path.ends_with(".json5") {
+        return Err(Error::Json5Unsupported(path.to_string()));
+    }
+
+    let content = read_config(project_root, path)?;
+    let config = if path == renovate::PACKAGE_JSON {
+        serde_json::from_str::<serde_json::Value>(&content).and_then(|mut manifest| {
+            let config = manifest.get_mut("renovate").map(serde_json::Value::take);
+            serde_json::from_value(config.unwrap_or_default())
+        })
+    } else {
+        serde_json::from_str(&content)
+    }
+    .map_err(|err| parse_error(path, err))?;
+
+    Ok((path, config))
+}
+
+fn load_dependabot_config(project_root: &Path) -> Result<(&'static str, DependabotConfig), Error> {
+    let Some(path) = dependabot::CONFIG_PATHS
+        .iter()
+        .find(|path| project_root.join(path).is_file())
+    else {
+        return Err(Error::NoConfig("Dependabot"));
+    };
+
+    let content = read_config(project_root, path)?;
+    let config = serde_yaml::from_str(&content).map_err(|err| parse_error(path, err))?;
+
+    Ok((path, config))
+}
+
+fn load_legacy_config(project_root: &Path) -> Result<LegacyConfig, Error> {
+    let path = dependabot::LEGACY_CONFIG_PATH;
+    if !project_root.join(path).is_file() {
+        return Err(Error::NoConfig("Dependabot Preview"));
+    }
+
+    let content = read_config(project_root, path)?;
+    serde_yaml::from_str(&content).map_err(|err| parse_error(path, err))
+}
+
+fn read_config(project_root: &Path, path: &str) -> Result<String, Error> {
+    fs::read_to_string(project_root.join(path)).map_err(|source| Error::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+fn parse_error(path: &str, err: impl std::fmt::Display) -> Error {
+    Error::Parse {
+        path: path.to_string(),
+        message: err.to_string(),
+    }
+}
+
+/// Renovate discovers package files on its own, while Dependabot needs one
+/// entry per ecosystem, so the entries are derived from the dependencies
+/// found in the project and filtered by the Renovate settings.
+pub fn renovate_to_dependabot(
+    config: &RenovateConfig,
+    dependencies: &[ProjectDependency],
+) -> Conversion<DependabotConfig> {
+    let mut warnings = Vec::new();
+
+    if config.enabled == Some(false) {
+        warnings.push("Renovate is disabled by this configuration".to_string());
+    }
+    if !config.extends.is_empty() {
+        warnings.push(format!(
+            "presets are not expanded and must be converted by hand: {}",
+            config.extends.join(", ")
+        ));
+    }
+    for key in config.other.keys() {
+        match renovate::ecosystem_for_manager(key) {
+            None => warnings.push(format!(
+                "`{}` has no Dependabot equivalent and was dropped",
+                key
+            )),
+            Some(ecosystem)
+                if config.manager_disabled(key) && !config.ecosystem_disabled(&ecosystem) =>
+            {
+                warnings.push(format!(
+                    "disabling only the `{}` manager has no Dependabot equivalent; \
+                     `{}` is still updated",
+                    key, ecosystem
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+
+    let enabled_ecosystems: Option<BTreeSet<Ecosystem>> = if config.enabled_managers.is_empty() {
+        None
+    } else {
+        let mut ecosystems = BTreeSet::new();
+        for manager in &config.enabled_managers {
+            match renovate::ecosystem_for_manager(manager) {
+                Some(ecosystem) => {
+                    ecosystems.insert(ecosystem);
+                }
+                None => warnings.push(format!(
+                    "manager `{}` has no Dependabot equivalent and was dropped",
+                    manager
+                )),
+            }
+        }
+        Some(ecosystems)
+    };
+
+    let ignore_paths = build_path_matcher(&config.ignore_paths, "ignorePaths", &mut warnings);
+    let include_paths = build_path_matcher(&config.include_paths, "includePaths", &mut warnings);
+
+    let mut directories: BTreeMap<&Ecosystem, BTreeSet<String>> = BTreeMap::new();
+    let mut unknown_ecosystems: BTreeSet<&Ecosystem> = BTreeSet::new();
+    for dep in dependencies {
+        if !dep.ecosystem.is_known() {
+            unknown_ecosystems.insert(&dep.ecosystem);
+            continue;
+        }
+        if let Some(enabled) = &enabled_ecosystems {
+            if !enabled.contains(&dep.ecosystem) {
+                continue;
+            }
+        }
+        if config.ecosystem_disabled(&dep.ecosystem) {
+            continue;
+        }
+        if dep.ecosystem == Ecosystem::Gitsubmodule
+            && !config.manager_explicitly_enabled("git-submodules")
+        {
+            continue;
+        }
+        if matches_path(&ignore_paths, &dep.directory) {
+            continue;
+        }
+        if !config.include_paths.is_empty() && !matches_path(&include_paths, &dep.directory) {
+            continue;
+        }
+
+        directories
+            .entry(&dep.ecosystem)
+            .or_default()
+            .insert(to_dependabot_directory(&dep.directory));
+    }
+
+    for ecosystem in unknown_ecosystems {
+        warnings.push(format!(
+            "`{}` dependencies were detected but Dependabot has no such ecosystem, \
+             so they get no entry",
+            ecosystem
+        ));
+    }
+
+    let schedule = schedule_from_renovate(&config.schedule, &mut warnings);
+    let schedule = ScheduleConfig {
+        timezone: config.timezone.clone(),
+        ..schedule
+    };
+
+    let ignore: Vec<IgnoreCondition> = config
+        .ignore_deps
+        .iter()
+        .map(|name| IgnoreCondition {
+            dependency_name: name.clone(),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut updates: Vec<UpdateConfig> = directories
+        .into_iter()
+        .map(|(ecosystem, directories)| {
+            let mut directories: Vec<String> = directories.into_iter().collect();
+            let (directory, directories) = if directories.len() == 1 {
+                (directories.pop(), Vec::new())
+            } else {
+                (None, directories)
+            };
+
+            UpdateConfig {
+                package_ecosystem: ecosystem.clone(),
+                directory,
+                directories,
+                schedule: schedule.clone(),
+                labels: config.labels.clone(),
+                open_pull_requests_limit: config.pr_concurrent_limit.filter(|limit| *limit > 0),
+                ignore: ignore.clone(),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    for (index, rule) in config.package_rules.iter().enumerate() {
+        apply_package_rule(index, rule, &mut updates, &mut warnings);
+    }
+
+    let updates = match config.base_branches.as_slice() {
+        [] => updates,
+        [branch] => updates
+            .into_iter()
+            .map(|update| UpdateConfig {
+                target_branch: Some(branch.clone()),
+                ..update
+            })
+            .collect(),
+        branches => branches
+            .iter()
+            .flat_map(|branch| {
+                updates.iter().map(|update| UpdateConfig {
+                    target_branch: Some(branch.clone()),
+                    ..update.clone()
+                })
+            })
+            .collect(),
+    };
+
+    Conversion {
+        config: DependabotConfig {
+            version: 2,
+            updates,
+            ..Default::default()
+        },
+        warnings,
+    }
+}
+
+fn apply_package_rule(
+    index: usize,
+    rule: &PackageRule,
+    updates: &mut Vec<UpdateConfig>,
+    warnings: &mut Vec<String>,
+) {
+    let context = format!("packageRules[{}]", index);
+
+    let mut ecosystems: Vec<Ecosystem> = Vec::new();
+    for manager in &rule.match_managers {
+        match renovate::ecosystem_for_manager(manager) {
+            Some(ecosystem) => ecosystems.push(ecosystem),
+            None => warnings.push(format!(
+                "{}: manager `{}` has no Dependabot equivalent and was dropped",
+                context, manager
+            )),
+        }
+    }
+    let applies_to = |update: &UpdateConfig| {
+        rule.match_managers.is_empty() || ecosystems.contains(&update.package_ecosystem)
+    };
+
+    let mut names = rule.match_package_names.clone();
+    for pattern in &rule.match_package_patterns {
+        match regex_to_wildcard(pattern) {
+            Some(wildcard) => names.push(wildcard),
+            None => warnings.push(format!(
+                "{}: package pattern `{}` cannot be expressed as a Dependabot wildcard",
+                context, pattern
+            )),
+        }
+    }
+
+    let update_types = update_types_to_dependabot(&rule.match_update_types, &context, warnings);
+
+    if !rule.match_dep_types.is_empty() {
+        warnings.push(format!(
+            "{}: matchDepTypes has no Dependabot equivalent and was ignored",
+            context
+        ));
+    }
+    if !rule.match_file_names.is_empty() {
+        warnings.push(format!(
+            "{}: matchFileNames has no Dependabot equivalent and was ignored",
+            context
+        ));
+    }
+    if !rule.schedule.is_empty() {
+        warnings.push(format!(
+            "{}: per-rule schedules are not converted; the global schedule applies",
+            context
+        ));
+    }
+    for key in rule.other.keys() {
+        warnings.push(format!(
+            "{}: `{}` has no Dependabot equivalent and was dropped",
+            context, key
+        ));
+    }
+
+    if rule.enabled == Some(false) {
+        if names.is_empty() && update_types.is_empty() {
+            if rule.match_managers.is_empty() {
+                warnings.push(format!(
+                    "{}: disabling every update has no Dependabot equivalent",
+                    context
+                ));
+            } else {
+                updates.retain(|update| !applies_to(update));
+            }
+            return;
+        }
+
+        if names.is_empty() {
+            names.push("*".to_string());
+        }
+        for update in updates.iter_mut().filter(|update| applies_to(update)) {
+            for name in &names {
+                update.ignore.push(IgnoreCondition {
+                    dependency_name: name.clone(),
+                    update_types: update_types.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+        return;
+    }
+
+    if let Some(group_name) = &rule.group_name {
+        let (patterns, exclude_patterns): (Vec<String>, Vec<String>) = names
+            .iter()
+            .cloned()
+            .partition(|name| !name.starts_with('!'));
+        let group = GroupConfig {
+            patterns: if patterns.is_empty() {
+                vec!["*".to_string()]
+            } else {
+                patterns
+            },
+            exclude_patterns: exclude_patterns
+                .into_iter()
+                .map(|name| name.trim_start_matches('!').to_string())
+                .collect(),
+            update_types: update_types.clone(),
+            ..Default::default()
+        };
+        for update in updates.iter_mut().filter(|update| applies_to(update)) {
+            update.groups.insert(slugify(group_name), group.clone());
+        }
+    }
+
+    if rule.labels.is_empty() {
+        return;
+    }
+    if !names.is_empty() || !update_types.is_empty() {
+        warnings.push(format!(
+            "{}: labels for a subset of packages have no Dependabot equivalent",
+            context
+        ));
+        return;
+    }
+    for update in updates.iter_mut().filter(|update| applies_to(update)) {
+        for label in &rule.labels {
+            if !update.labels.contains(label) {
+                update.labels.push(label.clone());
+            }
+        }
+    }
+}
+
+pub fn dependabot_to_renovate(config: &DependabotConfig) -> Conversion<RenovateConfig> {
+    let mut warnings = Vec::new();
+    let mut renovate = RenovateConfig {
+        schema: Some(renovate::SCHEMA_URL.to_string()),
+        ..Default::default()
+    };
+
+    for key in config.other.keys() {
+        warnings.push(format!(
+            "`{}` has no Renovate equivalent and was dropped",
+            key
+        ));
+    }
+
+    let mut entries = Vec::new();
+    for (index, update) in config.updates.iter().enumerate() {
+        let context = format!("updates[{}]", index);
+
+        let managers = renovate::managers_for_ecosystem(&update.package_ecosystem);
+        if managers.is_empty() {
+            warnings.push(format!(
+                "{}: Renovate has no manager for `{}`; the entry was dropped",
+                context, update.package_ecosystem
+            ));
+            continue;
+        }
+        for manager in &managers {
+            if !renovate.enabled_managers.iter().any(|m| m == manager) {
+                renovate.enabled_managers.push(manager.to_string());
+            }
+        }
+        if update.package_ecosystem == Ecosystem::Gitsubmodule {
+            renovate.other.insert(
+                "git-submodules".to_string(),
+                serde_json::json!({ "enabled": true }),
+            );
+        }
+
+        for key in update.other.keys() {
+            warnings.push(format!(
+                "{}: `{}` has no Renovate equivalent and was dropped",
+                context, key
+            ));
+        }
+
+        let schedule = schedule_to_renovate(&update.schedule, &context, &mut warnings);
+        let match_managers: Vec<String> = managers.iter().map(|m| m.to_string()).collect();
+        entries.push((context, match_managers, schedule, update));
+    }
+
+    let schedules: BTreeSet<&Vec<String>> = entries.iter().map(|(_, _, s, _)| s).collect();
+    if schedules.len() == 1 {
+        renovate.schedule = entries[0].2.clone();
+    } else {
+        for (_, match_managers, schedule, _) in &entries {
+            renovate.package_rules.push(PackageRule {
+                match_managers: match_managers.clone(),
+                schedule: schedule.clone(),
+                ..Default::default()
+            });
+        }
+    }
+
+    let timezones: BTreeSet<&str> = entries
+        .iter()
+        .filter_map(|(_, _, _, update)| update.schedule.timezone.as_deref())
+        .collect();
+    if timezones.len() > 1 {
+        warnings.push(format!(
+            "Renovate supports a single timezone; using `{}` for every manager",
+            timezones.iter().next().unwrap()
+        ));
+    }
+    renovate.timezone = timezones.iter().next().map(|timezone| timezone.to_string());
+
+    let labels: BTreeSet<&Vec<String>> = entries
+        .iter()
+        .map(|(_, _, _, update)| &update.labels)
+        .collect();
+    if labels.len() == 1 {
+        renovate.labels = entries[0].3.labels.clone();
+    } else {
+        for (_, match_managers, _, update) in &entries {
+            if !update.labels.is_empty() {
+                renovate.package_rules.push(PackageRule {
+                    match_managers: match_managers.clone(),
+                    labels: update.labels.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    let target_branches: BTreeSet<&str> = entries
+        .iter()
+        .filter_map(|(_, _, _, update)| update.target_branch.as_deref())
+        .collect();
+    if !target_branches.is_empty()
+        && entries
+            .iter()
+            .any(|(_, _, _, update)| update.target_branch.is_none())
+    {
+        warnings.push(
+            "some entries use the default branch and others a target-branch; \
+             Renovate applies baseBranches to every manager"
+                .to_string(),
+        );
+    }
+    renovate.base_branches = target_branches.iter().map(|b| b.to_string()).collect();
+
+    let limits: BTreeSet<u32> = entries
+        .iter()
+        .filter_map(|(_, _, _, update)| update.open_pull_requests_limit)
+        .collect();
+    if limits.len() > 1 {
+        warnings.push(format!(
+            "Renovate supports a single pull request limit; using {}",
+            limits.iter().next_back().unwrap()
+        ));
+    }
+    renovate.pr_concurrent_limit = limits.iter().next_back().copied();
+
+    let directories: BTreeSet<&str> = entries
+        .iter()
+        .flat_map(|(_, _, _, update)| update.all_directories())
+        .collect();
+    if !directories.iter().any(|directory| is_root(directory)) {
+        renovate.include_paths = directories
+            .iter()
+            .map(|directory| format!("{}/**", directory.trim_matches('/')))
+            .collect();
+    } else if directories.len() > 1 {
+        warnings.push(
+            "Renovate scans the whole repository, including directories \
+             Dependabot was not configured for"
+                .to_string(),
+        );
+    }
+
+    for (context, match_managers, _, update) in &entries {
+        for ignore in &update.ignore {
+            let update_types =
+                update_types_to_renovate(&ignore.update_types, context, &mut warnings);
+            if !ignore.versions.is_empty() {
+                warnings.push(format!(
+                    "{}: ignored versions of `{}` cannot be expressed in Renovate",
+                    context, ignore.dependency_name
+                ));
+                if update_types.is_empty() {
+                    continue;
+                }
+            }
+
+            renovate.package_rules.push(PackageRule {
+                match_managers: match_managers.clone(),
+                match_package_names: vec![ignore.dependency_name.clone()],
+                match_update_types: update_types,
+                enabled: Some(false),
+                ..Default::default()
+            });
+        }
+
+        for (name, group) in &update.groups {
+            if group.applies_to.as_deref() == Some("security-updates") {
+                warnings.push(format!(
+                    "{}: group `{}` applies to security updates, which Renovate \
+                     configures through vulnerabilityAlerts",
+                    context, name
+                ));
+            }
+            if group.dependency_type.is_some() {
+                warnings.push(format!(
+                    "{}: dependency-type of group `{}` has no Renovate equivalent",
+                    context, name
+                ));
+            }
+
+            let match_package_names = group
+                .patterns
+                .iter()
+                .cloned()
+                .chain(
+                    group
+                        .exclude_patterns
+                        .iter()
+                        .map(|pattern| format!("!{}", pattern)),
+                )
+                .filter(|pattern| pattern != "*")
+                .collect();
+            renovate.package_rules.push(PackageRule {
+                match_managers: match_managers.clone(),
+                match_package_names,
+                match_update_types: update_types_to_renovate(
+                    &group.update_types,
+                    context,
+                    &mut warnings,
+                ),
+                group_name: Some(name.clone()),
+                ..Default::default()
+            });
+        }
+    }
+
+    Conversion {
+        config: renovate,
+        warnings,
+    }
+}
+
+/// Migrates a Dependabot Preview configuration to version 2, following
+/// GitHub's documented mapping of `update_configs` onto `updates`.
+pub fn legacy_to_dependabot(config: &LegacyConfig) -> Conversion<DependabotConfig> {
+    let mut warnings = Vec::new();
+
+    if config.version != 1 {
+        warnings.push(format!(
+            "expected version 1, found version {}",
+            config.version
+        ));
+    }
+
+    let mut updates = Vec::new();
+    for (index, legacy) in config.update_configs.iter().enumerate() {
+        let context = format!("update_configs[{}]", index);
+
+        let Some(ecosystem) =
+            dependabot::ecosystem_for_legacy_package_manager(&legacy.package_manager)
+        else {
+            warnings.push(format!(
+                "{}: package manager `{}` is not supported by Dependabot; the entry was dropped",
+                context, legacy.package_manager
+            ));
+            continue;
+        };
+
+        let interval = match legacy.update_schedule.as_str() {
+            "live" => {
+                warnings.push(format!(
+                    "{}: live updates are no longer available and were converted to daily",
+                    context
+                ));
+                "daily"
+            }
+            interval @ ("daily" | "weekly" | "monthly") => interval,
+            interval => {
+                warnings.push(format!(
+                    "{}: unknown update_schedule `{}` was converted to weekly",
+                    context, interval
+                ));
+                "weekly"
+            }
+        };
+
+        let mut update = UpdateConfig {
+            package_ecosystem: ecosystem,
+            directory: Some(legacy.directory.clone()),
+            schedule: ScheduleConfig {
+                interval: interval.to_string(),
+                ..Default::default()
+            },
+            target_branch: legacy.target_branch.clone(),
+            labels: legacy.default_labels.clone(),
+            ..Default::default()
+        };
+
+        if !legacy.default_assignees.is_empty() {
+            update.other.insert(
+                "assignees".to_string(),
+                yaml_strings(&legacy.default_assignees),
+            );
+        }
+        if !legacy.default_reviewers.is_empty() {
+            warnings.push(format!(
+                "{}: default_reviewers were dropped; request reviews through a CODEOWNERS file",
+                context
+            ));
+        }
+
+        let mut allow = Vec::new();
+        for rule in &legacy.allowed_updates {
+            let matcher = &rule.matcher;
+            if matcher.update_type.as_deref().is_some_and(|t| t != "all") {
+                warnings.push(format!(
+                    "{}: allowed update_type `{}` has no version 2 equivalent",
+                    context,
+                    matcher.update_type.as_deref().unwrap_or_default()
+                ));
+            }
+
+            let mut condition = serde_yaml::Mapping::new();
+            if let Some(name) = &matcher.dependency_name {
+                condition.insert("dependency-name".into(), name.as_str().into());
+            }
+            if let Some(dependency_type) = &matcher.dependency_type {
+                condition.insert("dependency-type".into(), dependency_type.as_str().into());
+            }
+            if !condition.is_empty() {
+                allow.push(serde_yaml::Value::Mapping(condition));
+            }
+        }
+        if !allow.is_empty() {
+            update
+                .other
+                .insert("allow".to_string(), serde_yaml::Value::Sequence(allow));
+        }
+
+        for rule in &legacy.ignored_updates {
+            let Some(name) = &rule.matcher.dependency_name else {
+                warnings.push(format!(
+                    "{}: ignored_updates without a dependency_name were dropped",
+                    context
+                ));
+                continue;
+            };
+            update.ignore.push(IgnoreCondition {
+                dependency_name: name.clone(),
+                versions: rule.matcher.version_requirement.iter().cloned().collect(),
+                ..Default::default()
+            });
+        }
+
+        if let Some(strategy) = &legacy.version_requirement_updates {
+            let converted = match strategy.as_str() {
+                "auto" => Some("auto"),
+                "widen_ranges" => Some("widen"),
+                "increase_versions" => Some("increase"),
+                "increase_versions_if_necessary" => Some("increase-if-necessary"),
+                "off" => Some("lockfile-only"),
+                _ => None,
+            };
+            match converted {
+                Some(converted) => {
+                    update
+                        .other
+                        .insert("versioning-strategy".to_string(), converted.into());
+                }
+                None => warnings.push(format!(
+                    "{}: unknown version_requirement_updates `{}` was dropped",
+                    context, strategy
+                )),
+            }
+        }
+
+        if let Some(commit_message) = &legacy.commit_message {
+            let mut converted = serde_yaml::Mapping::new();
+            if let Some(prefix) = &commit_message.prefix {
+                converted.insert("prefix".into(), prefix.as_str().into());
+            }
+            if let Some(prefix) = &commit_message.prefix_development {
+                converted.insert("prefix-development".into(), prefix.as_str().into());
+            }
+            if commit_message.include_scope == Some(true) {
+                converted.insert("include".into(), "scope".into());
+            }
+            if !converted.is_empty() {
+                update.other.insert(
+                    "commit-message".to_string(),
+                    serde_yaml::Value::Mapping(converted),
+                );
+            }
+        }
+
+        for key in legacy.other.keys() {
+            warnings.push(format!(
+                "{}: `{}` has no version 2 equivalent and was dropped",
+                context, key
+            ));
+        }
+
+        updates.push(update);
+    }
+
+    Conversion {
+        config: DependabotConfig {
+            version: 2,
+            updates,
+            ..Default::default()
+        },
+        warnings,
+    }
+}
+
+fn yaml_strings(values: &[String]) -> serde_yaml::Value {
+    serde_yaml::Value::Sequence(values.iter().map(|v| v.as_str().into()).collect())
+}
+
+/// Maps Renovate schedules such as `before 4am on monday`, `every weekend` or
+/// cron expressions onto a Dependabot schedule.
+fn schedule_from_renovate(schedules: &[String], warnings: &mut Vec<String>) -> ScheduleConfig {
+    let daily = ScheduleConfig {
+        interval: "daily".to_string(),
+        ..Default::default()
+    };
+
+    let Some(schedule) = schedules.first() else {
+        return daily;
+    };
+    if schedules.len() > 1 {
+        warnings.push(format!(
+            "Dependabot supports a single schedule; only `{}` was converted",
+            schedule
+        ));
+    }
+
+    let text = schedule.trim().to_lowercase();
+    if text == "at any time" {
+        return daily;
+    }
+
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    if fields.len() == 5
+        && fields
+            .iter()
+            .all(|field| field.chars().all(|c| "0123456789*/,-".contains(c)))
+    {
+        return schedule_from_cron(&fields, schedule, warnings);
+    }
+
+    let mut exact = true;
+    let mut result = if text.contains("month") {
+        ScheduleConfig {
+            interval: "monthly".to_string(),
+            ..Default::default()
+        }
+    } else if text.contains("weekend") {
+        exact = false;
+        ScheduleConfig {
+            interval: "weekly".to_string(),
+            day: Some("saturday".to_string()),
+            ..Default::default()
+        }
+    } else if text.contains("weekday") {
+        exact = false;
+        daily.clone()
+    } else {
+        let days: Vec<&str> = WEEKDAYS
+            .iter()
+            .copied()
+            .filter(|day| text.contains(day))
+            .collect();
+        match days.as_slice() {
+            [] => daily.clone(),
+            [day] => ScheduleConfig {
+                interval: "weekly".to_string(),
+                day: Some(day.to_string()),
+                ..Default::default()
+            },
+            [day, ..] => {
+                exact = false;
+                ScheduleConfig {
+                    interval: "weekly".to_string(),
+                    day: Some(day.to_string()),
+                    ..Default::default()
+                }
+            }
+        }
+    };
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for window in words.windows(2) {
+        let hour = match (window[0], parse_hour(window[1])) {
+            ("after", Some(hour)) => hour,
+            ("before", Some(hour)) => (hour + 23) % 24,
+            _ => continue,
+        };
+        result.time = Some(format!("{:02}:00", hour));
+    }
+
+    let recognized = text.contains("every")
+        || text.contains(" on ")
+        || text.starts_with("on ")
+        || result.time.is_some();
+    if !exact || !recognized {
+        warnings.push(format!(
+            "schedule `{}` was approximated as `{}`",
+            schedule,
+            describe_schedule(&result)
+        ));
+    }
+
+    result
+}
+
+fn schedule_from_cron(
+    fields: &[&str],
+    schedule: &str,
+    warnings: &mut Vec<String>,
+) -> ScheduleConfig {
+    let [minute, hour, day_of_month, month, day_of_week] = fields else {
+        unreachable!("cron expressions have five fields");
+    };
+
+    let mut exact = *month == "*";
+    let mut result = if let Ok(day) = day_of_week.parse::<usize>() {
+        ScheduleConfig {
+            interval: "weekly".to_string(),
+            day: Some(WEEKDAYS[day % 7].to_string()),
+            ..Default::default()
+        }
+    } else if day_of_month.parse::<u32>().is_ok() {
+        exact &= *day_of_week == "*";
+        ScheduleConfig {
+            interval: "monthly".to_string(),
+            ..Default::default()
+        }
+    } else {
+        exact &= *day_of_week == "*" && *day_of_month == "*";
+        ScheduleConfig {
+            interval: "daily".to_string(),
+            ..Default::default()
+        }
+    };
+
+    let start_hour = hour.split(['-', ',']).next().unwrap_or_default();
+    if let Ok(hour_value) = start_hour.parse::<u32>() {
+        let minute_value = minute.parse::<u32>().unwrap_or(0);
+        result.time = Some(format!("{:02}:{:02}", hour_value, minute_value));
+    } else {
+        exact &= *hour == "*";
+    }
+
+    if !exact {
+        warnings.push(format!(
+            "schedule `{}` was approximated as `{}`",
+            schedule,
+            describe_schedule(&result)
+        ));
+    }
+
+    result
+}
+
+/// Renovate expresses schedules as windows; a cron expression whose minute
+/// field is `*` covers the hour Dependabot would start in.
+fn schedule_to_renovate(
+    schedule: &ScheduleConfig,
+    context: &str,
+    warnings: &mut Vec<String>,
+) -> Vec<String> {
+    let hour = schedule
+        .time
+        .as_deref()
+        .and_then(|time| time.split(':').next())
+        .and_then(|hour| hour.parse::<u32>().ok())
+        .map(|hour| hour.to_string())
+        .unwrap_or_else(|| "*".to_string());
+
+    let day_of_week = schedule
+        .day
+        .as_deref()
+        .and_then(|day| WEEKDAYS.iter().position(|d| d.eq_ignore_ascii_case(day)))
+        .unwrap_or(1);
+
+    let cron = match schedule.interval.as_str() {
+        "daily" if hour == "*" => return Vec::new(),
+        "daily" => format!("* {} * * *", hour),
+        "weekly" => format!("* {} * * {}", hour, day_of_week),
+        "monthly" => format!("* {} 1 * *", hour),
+        "quarterly" => format!("* {} 1 1,4,7,10 *", hour),
+        "semiannually" => format!("* {} 1 1,7 *", hour),
+        "yearly" => format!("* {} 1 1 *", hour),
+        "cron" => match schedule.cronjob.as_deref().map(str::split_whitespace) {
+            Some(fields) => {
+                let fields: Vec<&str> = fields.collect();
+                if fields.len() == 5 {
+                    format!("* {}", fields[1..].join(" "))
+                } else {
+                    warnings.push(format!(
+                        "{}: cronjob `{}` is not a five-field cron expression",
+                        context,
+                        schedule.cronjob.as_deref().unwrap_or_default()
+                    ));
+                    return Vec::new();
+                }
+            }
+            None => return Vec::new(),
+        },
+        interval => {
+            warnings.push(format!(
+                "{}: unknown schedule interval `{}` was dropped",
+                context, interval
+            ));
+            return Vec::new();
+        }
+    };
+
+    vec![cron]
+}
+
+fn update_types_to_dependabot(
+    update_types: &[String],
+    context: &str,
+    warnings: &mut Vec<String>,
+) -> Vec<String> {
+    update_types
+        .iter()
+        .filter_map(|update_type| match update_type.as_str() {
+            "major" | "minor" | "patch" => Some(format!("version-update:semver-{}", update_type)),
+            _ => {
+                warnings.push(format!(
+                    "{}: update type `{}` has no Dependabot equivalent",
+                    context, update_type
+                ));
+                None
+            }
+        })
+        .collect()
+}
+
+fn update_types_to_renovate(
+    update_types: &[String],
+    context: &str,
+    warnings: &mut Vec<String>,
+) -> Vec<String> {
+    update_types
+        .iter()
+        .filter_map(|update_type| {
+            let converted = update_type
+                .strip_prefix("version-update:semver-")
+                .or_else(|| update_type.strip_prefix("semver-"));
+            if converted.is_none() {
+                warnings.push(format!(
+                    "{}: update type `{}` has no Renovate equivalent",
+                    context, update_type
+                ));
+            }
+            converted.map(str::to_string)
+        })
+        .collect()
+}
+
+fn build_path_matcher(patterns: &[String], setting: &str, warnings: &mut Vec<String>) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        // Renovate treats patterns without glob characters as path prefixes.
+        let pattern = if pattern.contains(['*', '?', '[', '{']) {
+            pattern.clone()
+        } else {
+            format!("{}/**", pattern.trim_end_matches('/'))
+        };
+        match Glob::new(&pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(err) => warnings.push(format!("{}: invalid pattern: {}", setting, err)),
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+/// Matches a directory against patterns written for the files inside it.
+fn matches_path(matcher: &GlobSet, directory: &str) -> bool {
+    let directory = directory.trim_start_matches("./");
+    if directory == "." {
+        return matcher.is_match("_");
+    }
+    matcher.is_match(directory) || matcher.is_match(format!("{}/_", directory))
+}
+
+/// Converts an anchored Renovate package regex such as `^@angular/` into a
+/// Dependabot wildcard, if it only uses literal characters.
+fn regex_to_wildcard(pattern: &str) -> Option<String> {
+    let pattern = pattern
+        .strip_prefix('/')
+        .and_then(|pattern| pattern.strip_suffix('/'))
+        .unwrap_or(pattern);
+
+    let (prefix, body) = match pattern.strip_prefix('^') {
+        Some(body) => ("", body),
+        None => ("*", pattern),
+    };
+    let (body, suffix) = match body.strip_suffix('$') {
+        Some(body) => (body, ""),
+        None => (body, "*"),
+    };
+
+    let mut wildcard = String::new();
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if !escaped.is_ascii_alphanumeric() => wildcard.push(escaped),
+                _ => return None,
+            },
+            '.' if chars.peek() == Some(&'*') => {
+                chars.next();
+                wildcard.push('*');
+            }
+            '.' | '*' | '+' | '?' | '|' | '(' | ')' | '[' | ']' | '{' | '}' | '^' | '$' => {
+                return None
+            }
+            c => wildcard.push(c),
+        }
+    }
+
+    Some(format!("{}{}{}", prefix, wildcard, suffix).replace("**", "*"))
+}
+
+fn parse_hour(token: &str) -> Option<u32> {
+    let (digits, offset) = if let Some(digits) = token.strip_suffix("am") {
+        (digits, 0)
+    } else if let Some(digits) = token.strip_suffix("pm") {
+        (digits, 12)
+    } else {
+        (token, 0)
+    };
+
+    let hour: u32 = digits.split(':').next()?.parse().ok()?;
+    match (hour, offset) {
+        (12, 0) if token.ends_with("am") => Some(0),
+        (12, 12) => Some(12),
+        (hour, offset) if hour + offset < 24 => Some(hour + offset),
+        _ => None,
+    }
+}
+
+fn describe_schedule(schedule: &ScheduleConfig) -> String {
+    let mut description = schedule.interval.clone();
+    if let Some(day) = &schedule.day {
+        description.push_str(&format!(" on {}", day));
+    }
+    if let Some(time) = &schedule.time {
+        description.push_str(&format!(" at {}", time));
+    }
+    description
+}
+
+pub(crate) fn to_dependabot_directory(directory: &str) -> String {
+    if directory == "." {
+        "/".to_string()
+    } else {
+        format!("/{}", directory)
+    }
+}
+
+fn is_root(directory: &str) -> bool {
+    matches!(directory.trim(), "/" | "." | "")
+}
+
+fn slugify(name: &str) -> String {
+    let slug: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    slug.split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
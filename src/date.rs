@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Calendar dates, which are few enough here not to need a date library.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Converts days since 1970-01-01 to a Gregorian date, after Howard
+/// Hinnant's `civil_from_days`.
+pub(crate) fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z % 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The current date in UTC, as `YYYY-MM-DD`.
+pub(crate) fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let (year, month, day) = civil_from_days(seconds / 86400);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Whether `date` is written as `YYYY-MM-DD`, so that dates compare as
+/// strings.
+pub(crate) fn is_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, byte)| match index {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        })
+}
@@ -0,0 +1,295 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::Ecosystem;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Locations GitHub reads the Dependabot configuration from, in order.
+pub const CONFIG_PATHS: [&str; 2] = [".github/dependabot.yml", ".github/dependabot.yaml"];
+
+/// A Dependabot configuration file read from the project.
+#[derive(Debug)]
+pub(crate) struct DependabotFile {
+    /// One of [`CONFIG_PATHS`].
+    pub path: &'static str,
+    pub config: DependabotConfig,
+
+    /// Line of each entry of `updates`, if the file uses block style.
+    pub entry_lines: Vec<usize>,
+}
+
+impl DependabotFile {
+    pub fn parse(path: &'static str, content: &str) -> Result<Self, serde_yaml::Error> {
+        Ok(DependabotFile {
+            path,
+            config: serde_yaml::from_str(content)?,
+            entry_lines: update_entry_lines(content),
+        })
+    }
+
+    pub fn entry_line(&self, index: usize) -> Option<usize> {
+        self.entry_lines.get(index).copied()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DependabotConfig {
+    pub version: u8,
+    pub updates: Vec<UpdateConfig>,
+
+    /// Top-level settings this tool does not model, such as `registries`.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_yaml::Value>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct UpdateConfig {
+    pub package_ecosystem: Ecosystem,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directories: Vec<String>,
+
+    pub schedule: ScheduleConfig,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_pull_requests_limit: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<IgnoreCondition>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, GroupConfig>,
+
+    /// Entry settings this tool does not model, such as `registries` or
+    /// `commit-message`.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_yaml::Value>,
+}
+
+impl UpdateConfig {
+    /// All directories this entry applies to, whether it uses `directory` or
+    /// `directories`.
+    pub fn all_directories(&self) -> Vec<&str> {
+        self.directory
+            .iter()
+            .map(String::as_str)
+            .chain(self.directories.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ScheduleConfig {
+    pub interval: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cronjob: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct IgnoreCondition {
+    pub dependency_name: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub update_types: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct GroupConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_type: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_patterns: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub update_types: Vec<String>,
+}
+
+/// Finds the 1-based line of each item of the top-level `updates` list.
+///
+/// serde_yaml does not expose spans, so this scans the block-style layout
+/// that Dependabot configurations are written in.
+pub(crate) fn update_entry_lines(content: &str) -> Vec<usize> {
+    let mut lines = Vec::new();
+    let mut in_updates = false;
+    let mut item_indent = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        if indent == 0 && !trimmed.starts_with('-') {
+            in_updates = trimmed.starts_with("updates:");
+            item_indent = None;
+            continue;
+        }
+
+        if in_updates && (trimmed == "-" || trimmed.starts_with("- ")) {
+            match item_indent {
+                None => {
+                    item_indent = Some(indent);
+                    lines.push(index + 1);
+                }
+                Some(item_indent) if item_indent == indent => lines.push(index + 1),
+                Some(_) => {}
+            }
+        }
+    }
+
+    lines
+}
+
+/// Location of the Dependabot Preview configuration, which GitHub no longer
+/// reads.
+pub const LEGACY_CONFIG_PATH: &str = ".dependabot/config.yml";
+
+/// Dependabot Preview (version 1) configuration.
+#[derive(Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LegacyConfig {
+    pub version: u8,
+
+    #[serde(default)]
+    pub update_configs: Vec<LegacyUpdateConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LegacyUpdateConfig {
+    pub package_manager: String,
+    pub directory: String,
+    pub update_schedule: String,
+
+    #[serde(default)]
+    pub target_branch: Option<String>,
+
+    #[serde(default)]
+    pub default_labels: Vec<String>,
+
+    #[serde(default)]
+    pub default_reviewers: Vec<String>,
+
+    #[serde(default)]
+    pub default_assignees: Vec<String>,
+
+    #[serde(default)]
+    pub allowed_updates: Vec<LegacyUpdateRule>,
+
+    #[serde(default)]
+    pub ignored_updates: Vec<LegacyUpdateRule>,
+
+    #[serde(default)]
+    pub version_requirement_updates: Option<String>,
+
+    #[serde(default)]
+    pub commit_message: Option<LegacyCommitMessage>,
+
+    /// Settings without a version 2 equivalent, such as `automerged_updates`.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_yaml::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LegacyUpdateRule {
+    #[serde(rename = "match")]
+    pub matcher: LegacyMatch,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LegacyMatch {
+    #[serde(default)]
+    pub dependency_name: Option<String>,
+
+    #[serde(default)]
+    pub dependency_type: Option<String>,
+
+    #[serde(default)]
+    pub version_requirement: Option<String>,
+
+    #[serde(default)]
+    pub update_type: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LegacyCommitMessage {
+    #[serde(default)]
+    pub prefix: Option<String>,
+
+    #[serde(default)]
+    pub prefix_development: Option<String>,
+
+    #[serde(default)]
+    pub include_scope: Option<bool>,
+}
+
+/// Maps a Dependabot Preview `package_manager`, such as `rust:cargo` or
+/// `javascript`, to the version 2 `package-ecosystem`.
+pub fn ecosystem_for_legacy_package_manager(package_manager: &str) -> Option<Ecosystem> {
+    let ecosystem = match package_manager {
+        "docker" => Ecosystem::Docker,
+        "elm" => Ecosystem::Elm,
+        "github_actions" => Ecosystem::GithubActions,
+        "go:dep" => return None,
+        "java:gradle" => Ecosystem::Gradle,
+        "submodules" => Ecosystem::Gitsubmodule,
+        "terraform" => Ecosystem::Terraform,
+        _ => match package_manager.split(':').next().unwrap_or_default() {
+            "dotnet" => Ecosystem::Nuget,
+            "elixir" => Ecosystem::Mix,
+            "go" => Ecosystem::Gomod,
+            "java" => Ecosystem::Maven,
+            "javascript" => Ecosystem::Npm,
+            "php" => Ecosystem::Composer,
+            "python" => Ecosystem::Pip,
+            "ruby" => Ecosystem::Bundler,
+            "rust" => Ecosystem::Cargo,
+            _ => return None,
+        },
+    };
+
+    Some(ecosystem)
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Detection of the ecosystems a project uses from the files it contains.
+//!
+//! Every file of the project is shown to every [`Detector`] of the
+//! [`Analyzer`](crate::Analyzer). The built-in detectors recognize the
+//! manifests listed by [`Ecosystem::manifest_files`] and GitHub Actions
+//! workflows; library users can add their own for other build systems:
+//!
+//! ```
+//! use up2date::detect::{Detection, Detector, ProjectFile};
+//! use up2date::Ecosystem;
+//!
+//! /// Internal Go modules are vendored through `deps.lock` instead of `go.mod`.
+//! struct DepsLock;
+//!
+//! impl Detector for DepsLock {
+//!     fn name(&self) -> &str {
+//!         "deps-lock"
+//!     }
+//!
+//!     fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection> {
+//!         if file.file_name() == "deps.lock" {
+//!             vec![Detection::new(Ecosystem::Gomod, file)]
+//!         } else {
+//!             Vec::new()
+//!         }
+//!     }
+//! }
+//!
+//! let analyzer = up2date::Analyzer::new(".").detector(DepsLock);
+//! ```
+
+use crate::Ecosystem;
+use std::cell::OnceCell;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Recognizes the ecosystems a file of the project belongs to.
+pub trait Detector: Send + Sync {
+    /// Short name of the detector, shown in debug output.
+    fn name(&self) -> &str;
+
+    /// The ecosystems `file` is evidence of, usually none.
+    fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection>;
+}
+
+impl fmt::Debug for dyn Detector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Detector({})", self.name())
+    }
+}
+
+/// A file of the project shown to the detectors.
+pub struct ProjectFile<'a> {
+    root: &'a Path,
+    path: &'a Path,
+    content: OnceCell<Option<String>>,
+}
+
+impl<'a> ProjectFile<'a> {
+    pub(crate) fn new(root: &'a Path, path: &'a Path) -> Self {
+        ProjectFile {
+            root,
+            path,
+            content: OnceCell::new(),
+        }
+    }
+
+    /// Path of the file relative to the project root.
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
+    pub fn file_name(&self) -> &str {
+        self.path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+    }
+
+    /// Directory of the file relative to the project root, `.` for the root
+    /// itself.
+    pub fn directory(&self) -> String {
+        match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_string_lossy().to_string(),
+            _ => ".".to_string(),
+        }
+    }
+
+    /// Content of the file, read on first use and shared between detectors.
+    /// `None` if the file cannot be read or is not UTF-8.
+    pub fn content(&self) -> Option<&str> {
+        self.content
+            .get_or_init(|| fs::read_to_string(self.root.join(self.path)).ok())
+            .as_deref()
+    }
+}
+
+/// Dependencies of an ecosystem found by a [`Detector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Detection {
+    pub ecosystem: Ecosystem,
+
+    /// Directory the update tool has to look in, relative to the project
+    /// root, `.` for the root itself.
+    pub directory: String,
+
+    /// File the dependencies were detected from, relative to the project
+    /// root. Reports point at it.
+    pub evidence: String,
+}
+
+impl Detection {
+    /// Dependencies declared by `file`, in the directory of the file.
+    pub fn new(ecosystem: Ecosystem, file: &ProjectFile<'_>) -> Self {
+        Detection {
+            ecosystem,
+            directory: file.directory(),
+            evidence: file.path().to_string_lossy().to_string(),
+        }
+    }
+
+    /// Places the dependencies in another directory than the one of the
+    /// evidence, such as the root for workflows in `.github/workflows`.
+    pub fn with_directory(mut self, directory: impl Into<String>) -> Self {
+        self.directory = directory.into();
+        self
+    }
+}
+
+/// Detects the manifests listed by [`Ecosystem::manifest_files`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManifestDetector;
+
+impl Detector for ManifestDetector {
+    fn name(&self) -> &str {
+        "manifest"
+    }
+
+    fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection> {
+        Ecosystem::from_manifest(file.file_name())
+            .map(|ecosystem| Detection::new(ecosystem, file))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Detects GitHub Actions workflows, which Dependabot updates from the root
+/// directory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkflowDetector;
+
+impl Detector for WorkflowDetector {
+    fn name(&self) -> &str {
+        "github-workflow"
+    }
+
+    fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection> {
+        let is_workflow = file.path().parent() == Some(Path::new(".github/workflows"))
+            && (file.file_name().ends_with(".yml") || file.file_name().ends_with(".yaml"));
+        if !is_workflow {
+            return Vec::new();
+        }
+
+        vec![Detection::new(Ecosystem::GithubActions, file).with_directory(".")]
+    }
+}
+
+/// The detectors an [`Analyzer`](crate::Analyzer) starts with.
+pub fn builtin() -> Vec<Arc<dyn Detector>> {
+    vec![Arc::new(ManifestDetector), Arc::new(WorkflowDetector)]
+}
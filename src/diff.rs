@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Differences between two reports of the same project, such as those of two
+//! nightly runs.
+
+use crate::finding::{Finding, FindingKind};
+use crate::{DependencyReport, Ecosystem, Error, ProjectDependency, ReportSummary};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReportDiff {
+    /// Dependencies that were not updated automatically and now are.
+    pub covered: Vec<ProjectDependency>,
+
+    /// Dependencies that are not updated automatically, either because they
+    /// are new or because they lost their update configuration.
+    pub missing: Vec<ProjectDependency>,
+
+    /// Stale entries of the update configuration that were not reported
+    /// before.
+    pub stale: Vec<Finding>,
+
+    pub summary: SummaryDiff,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SummaryDiff {
+    pub old: ReportSummary,
+    pub new: ReportSummary,
+
+    pub total_ecosystems: i64,
+    pub configured_ecosystems: i64,
+    pub missing_ecosystems: i64,
+
+    /// Change of [`ReportSummary::coverage`] in percentage points.
+    pub coverage: f64,
+}
+
+impl ReportDiff {
+    pub fn new(old: &DependencyReport, new: &DependencyReport) -> Self {
+        let old_missing = missing_dependencies(old);
+        let new_missing = missing_dependencies(new);
+
+        let covered = new
+            .project_dependencies
+            .iter()
+            .filter(|dep| {
+                let key = (dep.ecosystem.clone(), dep.directory.clone());
+                old_missing.contains(&key) && !new_missing.contains(&key)
+            })
+            .cloned()
+            .collect();
+        let missing = new
+            .project_dependencies
+            .iter()
+            .filter(|dep| {
+                let key = (dep.ecosystem.clone(), dep.directory.clone());
+                new_missing.contains(&key) && !old_missing.contains(&key)
+            })
+            .cloned()
+            .collect();
+
+        // Identified like baseline entries, so that an entry for another
+        // branch or other directories is new.
+        let stale_key = |finding: &Finding| {
+            (
+                finding.file.clone(),
+                finding.ecosystem.clone(),
+                finding.directory.clone(),
+                finding.directories.clone(),
+                finding.target_branch.clone(),
+            )
+        };
+        let old_stale: HashSet<_> = stale_entries(old).map(stale_key).collect();
+        let stale = stale_entries(new)
+            .filter(|finding| !old_stale.contains(&stale_key(finding)))
+            .cloned()
+            .collect();
+
+        let delta = |old: usize, new: usize| new as i64 - old as i64;
+        let coverage = new.summary.coverage() - old.summary.coverage();
+        let summary = SummaryDiff {
+            total_ecosystems: delta(old.summary.total_ecosystems, new.summary.total_ecosystems),
+            configured_ecosystems: delta(
+                old.summary.configured_ecosystems,
+                new.summary.configured_ecosystems,
+            ),
+            missing_ecosystems: delta(
+                old.summary.missing_ecosystems,
+                new.summary.missing_ecosystems,
+            ),
+            coverage: (coverage * 100.0).round() / 100.0,
+            old: old.summary.clone(),
+            new: new.summary.clone(),
+        };
+
+        ReportDiff {
+            covered,
+            missing,
+            stale,
+            summary,
+        }
+    }
+
+    /// A report of what got worse: the newly missing dependencies and the
+    /// new stale entries, summarized as the newer report. Renderers of
+    /// findings, such as SARIF, show a diff through it.
+    pub fn regressions(&self) -> DependencyReport {
+        let mut missing_ecosystems: Vec<Ecosystem> = Vec::new();
+        for dep in &self.missing {
+            if !missing_ecosystems.contains(&dep.ecosystem) {
+                missing_ecosystems.push(dep.ecosystem.clone());
+            }
+        }
+
+        DependencyReport {
+            project_dependencies: self.missing.clone(),
+            dependabot_ecosystems: Vec::new(),
+            missing_from_dependabot: missing_ecosystems,
+            summary: self.summary.new.clone(),
+            findings: self.stale.clone(),
+            suppressed: Vec::new(),
+        }
+    }
+
+    /// Describes what the [regressions](Self::regressions) leave out: the
+    /// newly covered dependencies and the change of the summary. `None`
+    /// when nothing improved and the summary is unchanged.
+    pub fn improvements(&self) -> Option<String> {
+        let summary = &self.summary;
+        if self.covered.is_empty()
+            && summary.total_ecosystems == 0
+            && summary.configured_ecosystems == 0
+            && summary.missing_ecosystems == 0
+        {
+            return None;
+        }
+
+        let covered: Vec<String> = self
+            .covered
+            .iter()
+            .map(|dep| format!("{} in `{}`", dep.ecosystem, dep.directory))
+            .collect();
+        Some(format!(
+            "newly covered: {}; {} of {} ecosystems configured, {} of {} before; \
+             coverage changed by {:+} percentage points",
+            if covered.is_empty() {
+                "none".to_string()
+            } else {
+                covered.join(", ")
+            },
+            summary.new.configured_ecosystems,
+            summary.new.total_ecosystems,
+            summary.old.configured_ecosystems,
+            summary.old.total_ecosystems,
+            summary.coverage
+        ))
+    }
+}
+
+/// Reads a report serialized as JSON, or as YAML or TOML when the extension
+/// of `path` says so.
+pub fn read_report(path: &Path) -> Result<DependencyReport, Error> {
+    let display = path.display().to_string();
+    let content = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: display.clone(),
+        source,
+    })?;
+    let parse_error = |message: String| Error::Parse {
+        path: display.clone(),
+        message,
+    };
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("yaml" | "yml") => {
+            serde_yaml::from_str(&content).map_err(|err| parse_error(err.to_string()))
+        }
+        Some("toml") => {
+            toml::from_str(&content).map_err(|err| parse_error(err.to_string().trim_end().into()))
+        }
+        _ => serde_json::from_str(&content).map_err(|err| parse_error(err.to_string())),
+    }
+}
+
+/// The dependencies `report` reports as missing, leaving out suppressed
+/// ones.
+fn missing_dependencies(report: &DependencyReport) -> HashSet<(Ecosystem, String)> {
+    report
+        .coverage_findings()
+        .into_iter()
+        .filter_map(|finding| Some((finding.ecosystem?, finding.directory?)))
+        .collect()
+}
+
+fn stale_entries(report: &DependencyReport) -> impl Iterator<Item = &Finding> {
+    report
+        .findings
+        .iter()
+        .filter(|finding| finding.kind == FindingKind::StaleEntry)
+}
@@ -0,0 +1,316 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Package ecosystems as Dependabot names them, and what this tool knows
+//! about each of them.
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A value of Dependabot's `package-ecosystem`.
+///
+/// Serialized as the name Dependabot uses. Names Dependabot does not accept,
+/// including aliases such as `yarn`, deserialize to [`Ecosystem::Unknown`],
+/// so configurations written for newer Dependabot versions still load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum Ecosystem {
+    Bun,
+    Bundler,
+    Cargo,
+    Composer,
+    Devcontainers,
+    Docker,
+    DockerCompose,
+    DotnetSdk,
+    Elm,
+    Gitsubmodule,
+    GithubActions,
+    Gomod,
+    Gradle,
+    Helm,
+    Maven,
+    Mix,
+    Npm,
+    Nuget,
+    Pip,
+    Pub,
+    Swift,
+    Terraform,
+    Uv,
+    /// A name Dependabot does not accept. Never holds the name of one of the
+    /// other variants when converted from a name, and equal to that variant
+    /// when built with it anyway.
+    Unknown(String),
+}
+
+/// Names people commonly write instead of the Dependabot ecosystem, usually
+/// the language or a package manager Dependabot handles under another name.
+const ALIASES: [(&str, Ecosystem); 25] = [
+    ("actions", Ecosystem::GithubActions),
+    ("bundle", Ecosystem::Bundler),
+    ("dart", Ecosystem::Pub),
+    ("devcontainer", Ecosystem::Devcontainers),
+    ("dockerfile", Ecosystem::Docker),
+    ("dotnet", Ecosystem::Nuget),
+    ("elixir", Ecosystem::Mix),
+    ("flutter", Ecosystem::Pub),
+    ("git-submodule", Ecosystem::Gitsubmodule),
+    ("git-submodules", Ecosystem::Gitsubmodule),
+    ("go", Ecosystem::Gomod),
+    ("golang", Ecosystem::Gomod),
+    ("hex", Ecosystem::Mix),
+    ("javascript", Ecosystem::Npm),
+    ("php", Ecosystem::Composer),
+    ("pip-compile", Ecosystem::Pip),
+    ("pipenv", Ecosystem::Pip),
+    ("pnpm", Ecosystem::Npm),
+    ("poetry", Ecosystem::Pip),
+    ("python", Ecosystem::Pip),
+    ("ruby", Ecosystem::Bundler),
+    ("rust", Ecosystem::Cargo),
+    ("submodule", Ecosystem::Gitsubmodule),
+    ("submodules", Ecosystem::Gitsubmodule),
+    ("yarn", Ecosystem::Npm),
+];
+
+pub const ALIASES_NOTE: &str = "Dependabot names ecosystems after the package manager it \
+    runs: Poetry, Pipenv and pip-compile projects use `pip`, and Yarn and pnpm projects use `npm`";
+
+impl Ecosystem {
+    /// Every ecosystem Dependabot accepts.
+    pub const ALL: &'static [Ecosystem] = &[
+        Ecosystem::Bun,
+        Ecosystem::Bundler,
+        Ecosystem::Cargo,
+        Ecosystem::Composer,
+        Ecosystem::Devcontainers,
+        Ecosystem::Docker,
+        Ecosystem::DockerCompose,
+        Ecosystem::DotnetSdk,
+        Ecosystem::Elm,
+        Ecosystem::Gitsubmodule,
+        Ecosystem::GithubActions,
+        Ecosystem::Gomod,
+        Ecosystem::Gradle,
+        Ecosystem::Helm,
+        Ecosystem::Maven,
+        Ecosystem::Mix,
+        Ecosystem::Npm,
+        Ecosystem::Nuget,
+        Ecosystem::Pip,
+        Ecosystem::Pub,
+        Ecosystem::Swift,
+        Ecosystem::Terraform,
+        Ecosystem::Uv,
+    ];
+
+    /// The `package-ecosystem` value of this ecosystem.
+    pub fn name(&self) -> &str {
+        match self {
+            Ecosystem::Bun => "bun",
+            Ecosystem::Bundler => "bundler",
+            Ecosystem::Cargo => "cargo",
+            Ecosystem::Composer => "composer",
+            Ecosystem::Devcontainers => "devcontainers",
+            Ecosystem::Docker => "docker",
+            Ecosystem::DockerCompose => "docker-compose",
+            Ecosystem::DotnetSdk => "dotnet-sdk",
+            Ecosystem::Elm => "elm",
+            Ecosystem::Gitsubmodule => "gitsubmodule",
+            Ecosystem::GithubActions => "github-actions",
+            Ecosystem::Gomod => "gomod",
+            Ecosystem::Gradle => "gradle",
+            Ecosystem::Helm => "helm",
+            Ecosystem::Maven => "maven",
+            Ecosystem::Mix => "mix",
+            Ecosystem::Npm => "npm",
+            Ecosystem::Nuget => "nuget",
+            Ecosystem::Pip => "pip",
+            Ecosystem::Pub => "pub",
+            Ecosystem::Swift => "swift",
+            Ecosystem::Terraform => "terraform",
+            Ecosystem::Uv => "uv",
+            Ecosystem::Unknown(name) => name,
+        }
+    }
+
+    /// File names this tool detects the ecosystem from. Empty for ecosystems
+    /// it cannot detect.
+    pub fn manifest_files(&self) -> &'static [&'static str] {
+        match self {
+            Ecosystem::Cargo => &["Cargo.toml"],
+            Ecosystem::Docker => &["Dockerfile", "Containerfile"],
+            Ecosystem::Gitsubmodule => &[".gitmodules"],
+            Ecosystem::GithubActions => &["action.yml", "action.yaml"],
+            Ecosystem::Gomod => &["go.mod"],
+            Ecosystem::Npm => &["package.json"],
+            Ecosystem::Pip => &["requirements.txt", "pyproject.toml", "setup.py", "Pipfile"],
+            _ => &[],
+        }
+    }
+
+    /// The ecosystem a manifest with this file name belongs to.
+    pub fn from_manifest(file_name: &str) -> Option<Ecosystem> {
+        Ecosystem::ALL
+            .iter()
+            .find(|ecosystem| ecosystem.manifest_files().contains(&file_name))
+            .cloned()
+    }
+
+    /// Whether Dependabot accepts this ecosystem.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Ecosystem::Unknown(_))
+    }
+
+    /// Whether this tool detects manifests of this ecosystem, so that an
+    /// `updates` entry for it can be reported as stale.
+    pub fn is_detectable(&self) -> bool {
+        !self.manifest_files().is_empty()
+    }
+
+    /// Whether GitHub documents Dependabot support for this ecosystem as a
+    /// beta.
+    pub fn is_beta(&self) -> bool {
+        matches!(
+            self,
+            Ecosystem::Bun | Ecosystem::DockerCompose | Ecosystem::DotnetSdk
+        )
+    }
+
+    /// Whether Dependabot accepts the `vendor` option for this ecosystem.
+    pub fn supports_vendor(&self) -> bool {
+        matches!(self, Ecosystem::Bundler | Ecosystem::Gomod)
+    }
+
+    /// Resolves a name or a common alias, ignoring case, such as `Yarn` for
+    /// [`Ecosystem::Npm`].
+    pub fn from_alias(name: &str) -> Option<Ecosystem> {
+        let lowercase = name.trim().to_lowercase();
+        match Ecosystem::from(lowercase.as_str()) {
+            Ecosystem::Unknown(_) => ALIASES
+                .into_iter()
+                .find(|(alias, _)| *alias == lowercase)
+                .map(|(_, ecosystem)| ecosystem),
+            ecosystem => Some(ecosystem),
+        }
+    }
+
+    /// Suggests the known ecosystem closest to an unknown one.
+    pub fn suggest(&self) -> Option<Ecosystem> {
+        if let Some(ecosystem) = Ecosystem::from_alias(self.name()) {
+            return Some(ecosystem);
+        }
+
+        let lowercase = self.name().trim().to_lowercase();
+        let threshold = (lowercase.chars().count() / 3).max(2);
+        Ecosystem::ALL
+            .iter()
+            .map(|known| (levenshtein(&lowercase, known.name()), known))
+            .filter(|(distance, _)| *distance <= threshold)
+            .min_by(|(a, x), (b, y)| a.cmp(b).then_with(|| x.cmp(y)))
+            .map(|(_, known)| known.clone())
+    }
+}
+
+/// The empty name, which Dependabot rejects. Only meant as a placeholder in
+/// configuration structs built with `..Default::default()`.
+impl Default for Ecosystem {
+    fn default() -> Self {
+        Ecosystem::Unknown(String::new())
+    }
+}
+
+impl From<&str> for Ecosystem {
+    fn from(name: &str) -> Self {
+        Ecosystem::ALL
+            .iter()
+            .find(|ecosystem| ecosystem.name() == name)
+            .cloned()
+            .unwrap_or_else(|| Ecosystem::Unknown(name.to_string()))
+    }
+}
+
+impl From<String> for Ecosystem {
+    fn from(name: String) -> Self {
+        match Ecosystem::from(name.as_str()) {
+            Ecosystem::Unknown(_) => Ecosystem::Unknown(name),
+            ecosystem => ecosystem,
+        }
+    }
+}
+
+impl From<Ecosystem> for String {
+    fn from(ecosystem: Ecosystem) -> Self {
+        match ecosystem {
+            Ecosystem::Unknown(name) => name,
+            ecosystem => ecosystem.name().to_string(),
+        }
+    }
+}
+
+/// Compared by name, consistently with [`Ord`].
+impl PartialEq for Ecosystem {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for Ecosystem {}
+
+impl Hash for Ecosystem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl PartialEq<str> for Ecosystem {
+    fn eq(&self, other: &str) -> bool {
+        self.name() == other
+    }
+}
+
+impl PartialEq<&str> for Ecosystem {
+    fn eq(&self, other: &&str) -> bool {
+        self.name() == *other
+    }
+}
+
+/// Ordered by name, like the strings ecosystems used to be.
+impl Ord for Ecosystem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl PartialOrd for Ecosystem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Ecosystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::io;
+use std::path::PathBuf;
+
+/// Errors that stop an analysis, a conversion or the rendering of a report.
+///
+/// Problems with the configuration of the analyzed project are not errors
+/// but [`Finding`](crate::finding::Finding)s of the report.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("{}: not a directory", .0.display())]
+    NotADirectory(PathBuf),
+
+    #[error("invalid exclude pattern `{pattern}`: {message}")]
+    InvalidPattern { pattern: String, message: String },
+
+    /// The configuration a conversion reads from does not exist.
+    #[error("no {0} configuration found")]
+    NoConfig(&'static str),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{path}: {message}")]
+    Parse { path: String, message: String },
+
+    #[error("{0}: JSON5 configuration is not supported")]
+    Json5Unsupported(String),
+
+    #[error("failed to serialize {what} configuration: {message}")]
+    Serialize { what: &'static str, message: String },
+
+    #[error("{0}")]
+    Template(String),
+
+    #[error("failed to render {format} report: {message}")]
+    Render {
+        format: &'static str,
+        message: String,
+    },
+
+    /// The format describes a single report, such as an SBOM.
+    #[error("the {0} format cannot show the differences between two reports")]
+    UnsupportedDiffFormat(&'static str),
+
+    #[error("unknown {kind} `{value}`, expected one of: {}", .expected.join(", "))]
+    UnknownValue {
+        kind: &'static str,
+        value: String,
+        expected: Vec<&'static str>,
+    },
+}
+
+impl Error {
+    pub(crate) fn render(format: &'static str, err: impl std::fmt::Display) -> Self {
+        Error::Render {
+            format,
+            message: err.to_string(),
+        }
+    }
+}
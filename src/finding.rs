@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::dependabot::UpdateConfig;
+use crate::Ecosystem;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A problem with the update configuration of the project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub severity: Severity,
+
+    /// Path of the offending file, relative to the project root.
+    pub file: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecosystem: Option<Ecosystem>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+
+    /// Directories of the `updates` entries the finding is about, as written
+    /// in the Dependabot configuration.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directories: Vec<String>,
+
+    /// `target-branch` of the `updates` entries the finding is about.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+
+    /// Indices of the `updates` entries involved, for findings about
+    /// entries of the Dependabot configuration.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entries: Vec<usize>,
+
+    pub message: String,
+}
+
+impl Finding {
+    pub fn new(kind: FindingKind, file: impl Into<String>, message: impl Into<String>) -> Self {
+        Finding {
+            kind,
+            severity: kind.severity(),
+            file: file.into(),
+            line: None,
+            ecosystem: None,
+            directory: None,
+            directories: Vec::new(),
+            target_branch: None,
+            entries: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_line(mut self, line: Option<usize>) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_entries(mut self, entries: Vec<usize>) -> Self {
+        self.entries = entries;
+        self
+    }
+
+    /// Identifies the `updates` entries the finding is about by what they
+    /// configure rather than by their position, which changes whenever an
+    /// entry is inserted or moved.
+    pub fn with_updates<'a>(mut self, updates: impl IntoIterator<Item = &'a UpdateConfig>) -> Self {
+        for update in updates {
+            self.ecosystem = Some(update.package_ecosystem.clone());
+            self.target_branch = update.target_branch.clone();
+            for directory in update.all_directories() {
+                if !self
+                    .directories
+                    .iter()
+                    .any(|existing| existing == directory)
+                {
+                    self.directories.push(directory.to_string());
+                }
+            }
+        }
+        self.directories.sort();
+        self
+    }
+
+    pub fn with_dependency(mut self, ecosystem: &Ecosystem, directory: &str) -> Self {
+        self.ecosystem = Some(ecosystem.clone());
+        self.directory = Some(directory.to_string());
+        self
+    }
+}
+
+/// Why a finding is not reported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Suppression {
+    /// Where the suppression is written, such as the baseline file.
+    pub suppressed_by: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justification: Option<String>,
+}
+
+impl Suppression {
+    pub fn new(suppressed_by: impl Into<String>, justification: Option<String>) -> Self {
+        Suppression {
+            suppressed_by: suppressed_by.into(),
+            justification,
+        }
+    }
+}
+
+/// A finding that a baseline or an `up2date: ignore` comment suppresses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SuppressedFinding {
+    #[serde(flatten)]
+    pub finding: Finding,
+
+    #[serde(flatten)]
+    pub suppression: Suppression,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum FindingKind {
+    /// Dependencies of an ecosystem Dependabot is not configured for.
+    MissingEcosystem,
+    /// An `updates` entry for an ecosystem the project does not use.
+    StaleEntry,
+    /// A Dependabot Preview `.dependabot/config.yml`, which GitHub ignores.
+    LegacyConfig,
+    /// Both `.github/dependabot.yml` and `.github/dependabot.yaml` exist.
+    DuplicateConfigFile,
+    /// The Dependabot configuration cannot be read or parsed.
+    InvalidConfig,
+    /// Two `updates` entries share ecosystem, directory and target branch.
+    DuplicateUpdate,
+    /// The directories of two `updates` entries overlap.
+    OverlappingDirectories,
+    /// A `package-ecosystem` Dependabot does not know.
+    UnknownEcosystem,
+    /// A file or directory of the project cannot be read, so dependencies in
+    /// it are not checked.
+    UnreadablePath,
+    /// An `up2date: ignore` comment that suppresses no finding.
+    UnusedSuppression,
+}
+
+impl FindingKind {
+    pub const ALL: &'static [FindingKind] = &[
+        FindingKind::MissingEcosystem,
+        FindingKind::StaleEntry,
+        FindingKind::LegacyConfig,
+        FindingKind::DuplicateConfigFile,
+        FindingKind::InvalidConfig,
+        FindingKind::DuplicateUpdate,
+        FindingKind::OverlappingDirectories,
+        FindingKind::UnknownEcosystem,
+        FindingKind::UnreadablePath,
+        FindingKind::UnusedSuppression,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FindingKind::MissingEcosystem => "missing-ecosystem",
+            FindingKind::StaleEntry => "stale-entry",
+            FindingKind::LegacyConfig => "legacy-config",
+            FindingKind::DuplicateConfigFile => "duplicate-config-file",
+            FindingKind::InvalidConfig => "invalid-config",
+            FindingKind::DuplicateUpdate => "duplicate-update",
+            FindingKind::OverlappingDirectories => "overlapping-directories",
+            FindingKind::UnknownEcosystem => "unknown-ecosystem",
+            FindingKind::UnreadablePath => "unreadable-path",
+            FindingKind::UnusedSuppression => "unused-suppression",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            FindingKind::MissingEcosystem => "Dependencies are not updated by Dependabot",
+            FindingKind::StaleEntry => "Dependabot entry for an ecosystem the project does not use",
+            FindingKind::LegacyConfig => "Dependabot Preview configuration is no longer honoured",
+            FindingKind::DuplicateConfigFile => "Both dependabot.yml and dependabot.yaml exist",
+            FindingKind::InvalidConfig => "Dependabot configuration cannot be parsed",
+            FindingKind::DuplicateUpdate => "Duplicate Dependabot update entries",
+            FindingKind::OverlappingDirectories => "Overlapping Dependabot update directories",
+            FindingKind::UnknownEcosystem => "Unknown Dependabot package ecosystem",
+            FindingKind::UnreadablePath => "File or directory of the project cannot be read",
+            FindingKind::UnusedSuppression => "Suppression comment does not match any finding",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            FindingKind::StaleEntry
+            | FindingKind::OverlappingDirectories
+            | FindingKind::UnreadablePath
+            | FindingKind::UnusedSuppression => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
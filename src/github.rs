@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! GitHub Actions output: workflow commands that annotate the offending files,
+//! and the Markdown report appended to the job summary.
+
+use crate::diff::ReportDiff;
+use crate::finding::{Finding, Severity};
+use crate::output::markdown_report;
+use crate::{DependencyReport, Error};
+use std::fs::OpenOptions;
+use std::io::Write;
+
+pub fn annotations(report: &DependencyReport) -> String {
+    report
+        .all_findings()
+        .iter()
+        .map(|finding| format!("{}\n", annotation(finding)))
+        .collect()
+}
+
+/// Annotations of the [regressions](ReportDiff::regressions), and a notice
+/// with the newly covered dependencies and the change of coverage.
+pub fn diff_annotations(diff: &ReportDiff) -> String {
+    let mut annotations = annotations(&diff.regressions());
+    if let Some(improvements) = diff.improvements() {
+        annotations.push_str(&format!(
+            "::notice title=Coverage changes::{}\n",
+            escape_data(&improvements)
+        ));
+    }
+    annotations
+}
+
+/// Appends the Markdown report to the job summary, when running in GitHub
+/// Actions.
+pub fn append_step_summary(report: &DependencyReport) -> Result<(), Error> {
+    let Some(summary_path) = std::env::var_os("GITHUB_STEP_SUMMARY") else {
+        return Ok(());
+    };
+    let summary = markdown_report(report)?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&summary_path)
+        .and_then(|mut file| file.write_all(summary.as_bytes()))
+        .map_err(|source| Error::Io {
+            path: summary_path.to_string_lossy().into_owned(),
+            source,
+        })
+}
+
+fn annotation(finding: &Finding) -> String {
+    let command = match finding.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+
+    let mut properties = format!("file={}", escape_property(&finding.file));
+    if let Some(line) = finding.line {
+        properties.push_str(&format!(",line={}", line));
+    }
+    properties.push_str(&format!(
+        ",title={}",
+        escape_property(finding.kind.as_str())
+    ));
+
+    format!(
+        "::{} {}::{}",
+        command,
+        properties,
+        escape_data(&finding.message)
+    )
+}
+
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! GitLab Code Quality output, the CodeClimate-style JSON array shown in the
+//! merge request widget.
+
+use crate::finding::{Finding, FindingKind};
+use crate::{DependencyReport, Ecosystem, Error};
+use serde_json::json;
+
+pub fn codequality_report(report: &DependencyReport) -> Result<String, Error> {
+    let issues: Vec<serde_json::Value> = report
+        .all_findings()
+        .iter()
+        .map(|finding| {
+            json!({
+                "description": finding.message,
+                "check_name": finding.kind.as_str(),
+                "fingerprint": fingerprint(finding),
+                "severity": severity(finding.kind),
+                "location": {
+                    "path": finding.file,
+                    "lines": { "begin": finding.line.unwrap_or(1) },
+                },
+            })
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&issues)
+        .map_err(|err| Error::render("gitlab-codequality", err))?;
+    Ok(format!("{}\n", json))
+}
+
+fn severity(kind: FindingKind) -> &'static str {
+    match kind {
+        FindingKind::InvalidConfig => "blocker",
+        FindingKind::LegacyConfig
+        | FindingKind::DuplicateUpdate
+        | FindingKind::UnknownEcosystem => "critical",
+        FindingKind::MissingEcosystem | FindingKind::DuplicateConfigFile => "major",
+        FindingKind::StaleEntry
+        | FindingKind::OverlappingDirectories
+        | FindingKind::UnreadablePath
+        | FindingKind::UnusedSuppression => "minor",
+    }
+}
+
+/// GitLab tracks issues across pipelines by fingerprint, so it must only
+/// depend on what the finding is about, the way baseline entries identify
+/// findings. Neither the line nor the message, which names `updates`
+/// entries by index, is used, since both shift whenever the file is edited,
+/// and the file only identifies findings about no ecosystem, since a
+/// missing ecosystem points at whichever manifest of the directory comes
+/// first.
+fn fingerprint(finding: &Finding) -> String {
+    let directories = finding.directories.join("\n");
+    let file = match finding.ecosystem {
+        Some(_) => "",
+        None => finding.file.as_str(),
+    };
+    let key = [
+        finding.kind.as_str(),
+        file,
+        finding
+            .ecosystem
+            .as_ref()
+            .map(Ecosystem::name)
+            .unwrap_or_default(),
+        finding.directory.as_deref().unwrap_or_default(),
+        &directories,
+        finding.target_branch.as_deref().unwrap_or_default(),
+    ]
+    .join("\0");
+
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust releases.
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in key.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
@@ -0,0 +1,435 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Self-contained HTML report, meant to be published as a CI artifact for
+//! readers who do not want to dig through Markdown or JSON.
+
+use crate::convert::to_dependabot_directory;
+use crate::dependabot::{DependabotConfig, ScheduleConfig, UpdateConfig};
+use crate::diff::ReportDiff;
+use crate::finding::FindingKind;
+use crate::xml::escape;
+use crate::{DependencyReport, Ecosystem, Error};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+table { border-collapse: collapse; margin: 1rem 0; }
+th, td { border: 1px solid #d0d7de; padding: 0.3rem 0.7rem; text-align: left; }
+th { background: #f6f8fa; cursor: pointer; user-select: none; }
+td.covered { background: #dafbe1; }
+td.missing { background: #ffebe9; font-weight: bold; }
+td.stale { background: #fff8c5; }
+td.suppressed { background: #eaeef2; color: #59636e; }
+pre { background: #f6f8fa; padding: 1rem; overflow-x: auto; }
+table[data-filter="missing"] tbody tr:not([data-missing]),
+table[data-filter="stale"] tbody tr:not([data-stale]),
+table[data-filter="suppressed"] tbody tr:not([data-suppressed]) { display: none; }
+"#;
+
+/// Sorts the matrix by the clicked column and applies the row filter.
+const SCRIPT: &str = r#"
+const matrix = document.getElementById("matrix");
+matrix.querySelectorAll("th").forEach((th, column) => {
+  th.addEventListener("click", () => {
+    const ascending = th.dataset.order !== "asc";
+    matrix.querySelectorAll("th").forEach((other) => delete other.dataset.order);
+    th.dataset.order = ascending ? "asc" : "desc";
+    const body = matrix.tBodies[0];
+    const rows = Array.from(body.rows);
+    rows.sort((a, b) => {
+      const order = a.cells[column].textContent.localeCompare(b.cells[column].textContent);
+      return ascending ? order : -order;
+    });
+    rows.forEach((row) => body.appendChild(row));
+  });
+});
+document.querySelectorAll("input[name=filter]").forEach((input) => {
+  input.addEventListener("change", () => { matrix.dataset.filter = input.value; });
+});
+"#;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Covered,
+    Missing,
+    Stale,
+    Suppressed,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Covered => "covered",
+            Status::Missing => "missing",
+            Status::Stale => "stale",
+            Status::Suppressed => "suppressed",
+        }
+    }
+}
+
+pub fn html_report(report: &DependencyReport) -> Result<String, Error> {
+    let matrix = coverage_matrix(report);
+    let ecosystems: BTreeSet<&str> = matrix
+        .values()
+        .flat_map(|row| row.keys().copied())
+        .collect();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Dependabot Coverage Report</title>\n");
+    writeln!(html, "<style>{}</style>", STYLE).unwrap();
+    html.push_str("</head>\n<body>\n<h1>Dependabot Coverage Report</h1>\n");
+
+    html.push_str("<h2>Summary</h2>\n<ul>\n");
+    writeln!(
+        html,
+        "<li><strong>Total ecosystems found</strong>: {}</li>",
+        report.summary.total_ecosystems
+    )
+    .unwrap();
+    writeln!(
+        html,
+        "<li><strong>Configured in dependabot</strong>: {}</li>",
+        report.summary.configured_ecosystems
+    )
+    .unwrap();
+    writeln!(
+        html,
+        "<li><strong>Missing from dependabot</strong>: {}</li>",
+        report.summary.missing_ecosystems
+    )
+    .unwrap();
+    html.push_str("</ul>\n");
+
+    html.push_str("<h2>Coverage</h2>\n");
+    if matrix.is_empty() {
+        html.push_str("<p>No dependencies found.</p>\n");
+    } else {
+        html.push_str("<fieldset>\n<legend>Show directories</legend>\n");
+        for (value, label) in [
+            ("all", "All"),
+            ("missing", "Missing"),
+            ("stale", "Stale"),
+            ("suppressed", "Suppressed"),
+        ] {
+            writeln!(
+                html,
+                "<label><input type=\"radio\" name=\"filter\" value=\"{}\"{}> {}</label>",
+                value,
+                if value == "all" { " checked" } else { "" },
+                label
+            )
+            .unwrap();
+        }
+        html.push_str("</fieldset>\n");
+
+        html.push_str("<table id=\"matrix\" data-filter=\"all\">\n<thead>\n<tr><th>Directory</th>");
+        for ecosystem in &ecosystems {
+            write!(html, "<th>{}</th>", escape(ecosystem)).unwrap();
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+        for (directory, row) in &matrix {
+            html.push_str("<tr");
+            if row.values().any(|status| *status == Status::Missing) {
+                html.push_str(" data-missing");
+            }
+            if row.values().any(|status| *status == Status::Stale) {
+                html.push_str(" data-stale");
+            }
+            if row.values().any(|status| *status == Status::Suppressed) {
+                html.push_str(" data-suppressed");
+            }
+            write!(html, "><td><code>{}</code></td>", escape(directory)).unwrap();
+            for ecosystem in &ecosystems {
+                match row.get(ecosystem) {
+                    Some(status) => write!(
+                        html,
+                        "<td class=\"{}\">{}</td>",
+                        status.as_str(),
+                        status.as_str()
+                    )
+                    .unwrap(),
+                    None => html.push_str("<td></td>"),
+                }
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n");
+    }
+
+    let findings = report.all_findings();
+    if !findings.is_empty() {
+        html.push_str("<h2>Findings</h2>\n<ul>\n");
+        for finding in &findings {
+            write!(
+                html,
+                "<li><strong>{}</strong> <code>{}",
+                finding.severity,
+                escape(&finding.file)
+            )
+            .unwrap();
+            if let Some(line) = finding.line {
+                write!(html, ":{}", line).unwrap();
+            }
+            writeln!(
+                html,
+                "</code>: {} ({})</li>",
+                escape(&finding.message),
+                finding.kind
+            )
+            .unwrap();
+        }
+        html.push_str("</ul>\n");
+    }
+
+    if !report.suppressed.is_empty() {
+        html.push_str("<h2>Suppressed</h2>\n<ul>\n");
+        for suppressed in &report.suppressed {
+            let finding = &suppressed.finding;
+            write!(html, "<li><code>{}", escape(&finding.file)).unwrap();
+            if let Some(line) = finding.line {
+                write!(html, ":{}", line).unwrap();
+            }
+            write!(
+                html,
+                "</code>: {} ({}, suppressed by <code>{}</code>",
+                escape(&finding.message),
+                finding.kind,
+                escape(&suppressed.suppression.suppressed_by)
+            )
+            .unwrap();
+            if let Some(justification) = &suppressed.suppression.justification {
+                write!(html, ": {}", escape(justification)).unwrap();
+            }
+            html.push_str(")</li>\n");
+        }
+        html.push_str("</ul>\n");
+    }
+
+    if let Some(snippet) = fix_snippet(report)? {
+        html.push_str("<h2>How to fix</h2>\n");
+        html.push_str(
+            "<p>Add these entries to the <code>updates</code> list of \
+             <code>.github/dependabot.yml</code>, or use them as the whole file \
+             if the project has no Dependabot configuration yet:</p>\n",
+        );
+        writeln!(html, "<pre><code>{}</code></pre>", escape(&snippet)).unwrap();
+    }
+    let unsupported = unsupported_missing(report);
+    if !unsupported.is_empty() {
+        let names: Vec<String> = unsupported
+            .iter()
+            .map(|ecosystem| format!("<code>{}</code>", escape(ecosystem.name())))
+            .collect();
+        writeln!(
+            html,
+            "<p>Dependabot has no ecosystem named {}, so no entry can cover it; \
+             suppress it with an <code>up2date: ignore</code> comment instead.</p>",
+            names.join(", ")
+        )
+        .unwrap();
+    }
+
+    if !matrix.is_empty() {
+        writeln!(html, "<script>{}</script>", SCRIPT).unwrap();
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+/// The changes between two reports, laid out like the Markdown diff.
+pub fn html_diff(diff: &ReportDiff) -> String {
+    let summary = &diff.summary;
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Dependabot Coverage Changes</title>\n");
+    writeln!(html, "<style>{}</style>", STYLE).unwrap();
+    html.push_str("</head>\n<body>\n<h1>Dependabot Coverage Changes</h1>\n");
+
+    html.push_str("<h2>Summary</h2>\n<table>\n<thead>\n");
+    html.push_str("<tr><th></th><th>Before</th><th>After</th><th>Change</th></tr>\n");
+    html.push_str("</thead>\n<tbody>\n");
+    for (label, old, new, change) in [
+        (
+            "Total ecosystems found",
+            summary.old.total_ecosystems,
+            summary.new.total_ecosystems,
+            summary.total_ecosystems,
+        ),
+        (
+            "Configured in dependabot",
+            summary.old.configured_ecosystems,
+            summary.new.configured_ecosystems,
+            summary.configured_ecosystems,
+        ),
+        (
+            "Missing from dependabot",
+            summary.old.missing_ecosystems,
+            summary.new.missing_ecosystems,
+            summary.missing_ecosystems,
+        ),
+    ] {
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:+}</td></tr>",
+            label, old, new, change
+        )
+        .unwrap();
+    }
+    html.push_str("</tbody>\n</table>\n");
+    writeln!(
+        html,
+        "<p>Coverage changed by {:+} percentage points.</p>",
+        summary.coverage
+    )
+    .unwrap();
+
+    if diff.covered.is_empty() && diff.missing.is_empty() && diff.stale.is_empty() {
+        html.push_str("<p>No dependencies changed coverage and no stale entries were added.</p>\n");
+    }
+    for (title, dependencies) in [
+        ("Newly Covered", &diff.covered),
+        ("Newly Missing", &diff.missing),
+    ] {
+        if dependencies.is_empty() {
+            continue;
+        }
+        writeln!(html, "<h2>{}</h2>\n<ul>", title).unwrap();
+        for dep in dependencies {
+            writeln!(
+                html,
+                "<li><strong>{}</strong> in <code>{}</code></li>",
+                escape(dep.ecosystem.name()),
+                escape(&dep.directory)
+            )
+            .unwrap();
+        }
+        html.push_str("</ul>\n");
+    }
+    if !diff.stale.is_empty() {
+        html.push_str("<h2>New Stale Entries</h2>\n<ul>\n");
+        for finding in &diff.stale {
+            write!(html, "<li><code>{}", escape(&finding.file)).unwrap();
+            if let Some(line) = finding.line {
+                write!(html, ":{}", line).unwrap();
+            }
+            writeln!(html, "</code>: {}</li>", escape(&finding.message)).unwrap();
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Coverage status of every ecosystem in every directory, including the
+/// directories of stale `updates` entries.
+fn coverage_matrix(report: &DependencyReport) -> BTreeMap<String, BTreeMap<&str, Status>> {
+    let mut matrix: BTreeMap<String, BTreeMap<&str, Status>> = BTreeMap::new();
+
+    for dep in &report.project_dependencies {
+        let status = if !report.missing_from_dependabot.contains(&dep.ecosystem) {
+            Status::Covered
+        } else if report.is_suppressed(&dep.ecosystem, &dep.directory) {
+            Status::Suppressed
+        } else {
+            Status::Missing
+        };
+        matrix
+            .entry(dep.directory.clone())
+            .or_default()
+            .insert(dep.ecosystem.name(), status);
+    }
+
+    for finding in &report.findings {
+        if finding.kind != FindingKind::StaleEntry {
+            continue;
+        }
+        let Some(ecosystem) = &finding.ecosystem else {
+            continue;
+        };
+        for directory in &finding.directories {
+            let directory = match directory.trim_matches('/') {
+                "" => ".".to_string(),
+                directory => directory.to_string(),
+            };
+            matrix
+                .entry(directory)
+                .or_default()
+                .insert(ecosystem.name(), Status::Stale);
+        }
+    }
+
+    matrix
+}
+
+/// Missing ecosystems that are not suppressed, such as those of
+/// `[[detect]]` rules, which Dependabot does not know.
+fn unsupported_missing(report: &DependencyReport) -> BTreeSet<&Ecosystem> {
+    report
+        .project_dependencies
+        .iter()
+        .filter(|dep| {
+            !dep.ecosystem.is_known()
+                && report.missing_from_dependabot.contains(&dep.ecosystem)
+                && !report.is_suppressed(&dep.ecosystem, &dep.directory)
+        })
+        .map(|dep| &dep.ecosystem)
+        .collect()
+}
+
+/// `updates` entries that would cover every missing dependency that is not
+/// suppressed, leaving out ecosystems Dependabot does not know.
+fn fix_snippet(report: &DependencyReport) -> Result<Option<String>, Error> {
+    let mut missing: BTreeMap<&Ecosystem, BTreeSet<String>> = BTreeMap::new();
+    for dep in &report.project_dependencies {
+        if dep.ecosystem.is_known()
+            && report.missing_from_dependabot.contains(&dep.ecosystem)
+            && !report.is_suppressed(&dep.ecosystem, &dep.directory)
+        {
+            missing
+                .entry(&dep.ecosystem)
+                .or_default()
+                .insert(to_dependabot_directory(&dep.directory));
+        }
+    }
+    if missing.is_empty() {
+        return Ok(None);
+    }
+
+    let updates = missing
+        .into_iter()
+        .map(|(ecosystem, directories)| {
+            let mut directories: Vec<String> = directories.into_iter().collect();
+            let (directory, directories) = if directories.len() == 1 {
+                (directories.pop(), Vec::new())
+            } else {
+                (None, directories)
+            };
+
+            UpdateConfig {
+                package_ecosystem: ecosystem.clone(),
+                directory,
+                directories,
+                schedule: ScheduleConfig {
+                    interval: "weekly".to_string(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let config = DependabotConfig {
+        version: 2,
+        updates,
+        ..Default::default()
+    };
+    serde_yaml::to_string(&config)
+        .map(Some)
+        .map_err(|err| Error::render("html", err))
+}
@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! JUnit XML output, where every detected ecosystem and directory is a test
+//! case that fails when Dependabot does not update it, or is skipped when the
+//! missing coverage is suppressed.
+
+use crate::xml::escape;
+use crate::{DependencyReport, ProjectDependency};
+use std::fmt::Write;
+
+pub fn junit_report(report: &DependencyReport) -> String {
+    let mut dependencies: Vec<&ProjectDependency> = report.project_dependencies.iter().collect();
+    dependencies.sort_by(|a, b| (&a.ecosystem, &a.directory).cmp(&(&b.ecosystem, &b.directory)));
+
+    let tests = dependencies.len();
+    let missing = |dep: &ProjectDependency| report.missing_from_dependabot.contains(&dep.ecosystem);
+    let skipped = dependencies
+        .iter()
+        .filter(|dep| missing(dep) && report.is_suppressed(&dep.ecosystem, &dep.directory))
+        .count();
+    let failures = dependencies.iter().filter(|dep| missing(dep)).count() - skipped;
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        xml,
+        "<testsuites name=\"up2date\" tests=\"{}\" failures=\"{}\" errors=\"0\">",
+        tests, failures
+    )
+    .unwrap();
+    writeln!(
+        xml,
+        "  <testsuite name=\"dependabot-coverage\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">",
+        tests, failures, skipped
+    )
+    .unwrap();
+
+    for dep in dependencies {
+        let manifests = dep.manifests.join(", ");
+        write!(
+            xml,
+            "    <testcase classname=\"up2date.{}\" name=\"{} in {}\"",
+            escape(dep.ecosystem.name()),
+            escape(dep.ecosystem.name()),
+            escape(&dep.directory)
+        )
+        .unwrap();
+        if let Some(manifest) = dep.manifests.first() {
+            write!(xml, " file=\"{}\"", escape(manifest)).unwrap();
+        }
+
+        if !missing(dep) {
+            xml.push_str("/>\n");
+            continue;
+        }
+
+        if let Some(suppression) = report.suppression(&dep.ecosystem, &dep.directory) {
+            let message = match &suppression.justification {
+                Some(justification) => {
+                    format!(
+                        "suppressed by {}: {}",
+                        suppression.suppressed_by, justification
+                    )
+                }
+                None => format!("suppressed by {}", suppression.suppressed_by),
+            };
+            writeln!(
+                xml,
+                ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                escape(&message)
+            )
+            .unwrap();
+            continue;
+        }
+
+        let message = format!(
+            "{} is not updated by Dependabot: no `{}` entry in the Dependabot configuration",
+            if manifests.is_empty() {
+                &dep.directory
+            } else {
+                &manifests
+            },
+            dep.ecosystem
+        );
+        xml.push_str(">\n");
+        writeln!(
+            xml,
+            "      <failure type=\"missing-ecosystem\" message=\"{}\">{}</failure>",
+            escape(&message),
+            escape(&message)
+        )
+        .unwrap();
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
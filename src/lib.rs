@@ -0,0 +1,621 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Checks whether all dependencies of a repository are configured for
+//! automatic updates.
+//!
+//! ```no_run
+//! use up2date::{Analyzer, Provider};
+//!
+//! let report = Analyzer::new(".")
+//!     .exclude("vendor/**")
+//!     .providers([Provider::Dependabot, Provider::Renovate])
+//!     .analyze()?;
+//! for ecosystem in &report.missing_from_dependabot {
+//!     println!("{} is not updated automatically", ecosystem);
+//! }
+//! # Ok::<(), up2date::Error>(())
+//! ```
+
+mod badge;
+pub mod baseline;
+pub mod config;
+pub mod convert;
+mod date;
+pub mod dependabot;
+pub mod detect;
+pub mod diff;
+pub mod ecosystem;
+mod error;
+pub mod finding;
+mod github;
+mod gitlab;
+mod html;
+mod junit;
+mod metrics;
+pub mod output;
+pub mod ratchet;
+pub mod renovate;
+mod sarif;
+mod sbom;
+mod suppression;
+mod template;
+mod validate;
+mod xml;
+
+pub use ecosystem::Ecosystem;
+pub use error::Error;
+
+use config::ProjectConfig;
+use dependabot::DependabotFile;
+use detect::{Detector, ProjectFile};
+use finding::{Finding, FindingKind, Severity, SuppressedFinding, Suppression};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+/// The result of an analysis.
+///
+/// The serialized form is the `--format json` output of the command line
+/// tool and only gains fields between releases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DependencyReport {
+    pub project_dependencies: Vec<ProjectDependency>,
+
+    /// Ecosystems the update tools are configured for. Named after
+    /// Dependabot, the only provider checked by default.
+    pub dependabot_ecosystems: Vec<Ecosystem>,
+
+    /// Ecosystems of the project that no update tool is configured for.
+    pub missing_from_dependabot: Vec<Ecosystem>,
+
+    pub summary: ReportSummary,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<Finding>,
+
+    /// Findings left out of `findings` and of
+    /// [`coverage_findings`](DependencyReport::coverage_findings).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<SuppressedFinding>,
+}
+
+impl DependencyReport {
+    /// Whether the report contains findings that must fail the check.
+    pub fn has_blocking_findings(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.severity == Severity::Error)
+    }
+
+    /// One finding per directory whose dependencies Dependabot does not
+    /// update, pointing at the manifest they were detected from. Suppressed
+    /// directories are left out.
+    pub fn coverage_findings(&self) -> Vec<Finding> {
+        let mut missing: Vec<&ProjectDependency> = self
+            .project_dependencies
+            .iter()
+            .filter(|dep| self.missing_from_dependabot.contains(&dep.ecosystem))
+            .filter(|dep| !self.is_suppressed(&dep.ecosystem, &dep.directory))
+            .collect();
+        missing.sort_by(|a, b| (&a.ecosystem, &a.directory).cmp(&(&b.ecosystem, &b.directory)));
+
+        missing
+            .into_iter()
+            .map(ProjectDependency::missing_finding)
+            .collect()
+    }
+
+    /// Missing coverage, stale entries and invalid configuration together.
+    pub fn all_findings(&self) -> Vec<Finding> {
+        let mut findings = self.coverage_findings();
+        findings.extend(self.findings.iter().cloned());
+        findings
+    }
+
+    /// Whether missing coverage of `ecosystem` in `directory` is
+    /// suppressed.
+    pub fn is_suppressed(&self, ecosystem: &Ecosystem, directory: &str) -> bool {
+        self.suppression(ecosystem, directory).is_some()
+    }
+
+    /// What suppresses missing coverage of `ecosystem` in `directory`.
+    pub(crate) fn suppression(
+        &self,
+        ecosystem: &Ecosystem,
+        directory: &str,
+    ) -> Option<&Suppression> {
+        self.suppressed
+            .iter()
+            .find(|suppressed| {
+                suppressed.finding.kind == FindingKind::MissingEcosystem
+                    && suppressed.finding.ecosystem.as_ref() == Some(ecosystem)
+                    && suppressed.finding.directory.as_deref() == Some(directory)
+            })
+            .map(|suppressed| &suppressed.suppression)
+    }
+
+    /// Moves the findings `rule` returns a suppression for, including
+    /// missing coverage, to [`suppressed`](DependencyReport::suppressed).
+    pub fn suppress(&mut self, mut rule: impl FnMut(&Finding) -> Option<Suppression>) {
+        for finding in self.coverage_findings() {
+            if let Some(suppression) = rule(&finding) {
+                self.suppressed.push(SuppressedFinding {
+                    finding,
+                    suppression,
+                });
+            }
+        }
+
+        let mut findings = Vec::new();
+        for finding in std::mem::take(&mut self.findings) {
+            match rule(&finding) {
+                Some(suppression) => self.suppressed.push(SuppressedFinding {
+                    finding,
+                    suppression,
+                }),
+                None => findings.push(finding),
+            }
+        }
+        self.findings = findings;
+    }
+}
+
+/// Dependencies of one ecosystem in one directory of the project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectDependency {
+    pub ecosystem: Ecosystem,
+
+    /// Directory relative to the project root, `.` for the root itself.
+    pub directory: String,
+
+    /// Files the dependency was detected from, relative to the project root.
+    #[serde(skip)]
+    pub manifests: Vec<String>,
+}
+
+impl ProjectDependency {
+    /// Dependencies of `ecosystem` in `directory`, relative to the project
+    /// root, detected from `manifests`.
+    pub fn new(ecosystem: Ecosystem, directory: impl Into<String>, manifests: Vec<String>) -> Self {
+        ProjectDependency {
+            ecosystem,
+            directory: directory.into(),
+            manifests,
+        }
+    }
+
+    /// The finding reporting that Dependabot does not update the
+    /// dependency, pointing at the manifest it was detected from.
+    pub(crate) fn missing_finding(&self) -> Finding {
+        let file = self
+            .manifests
+            .first()
+            .cloned()
+            .unwrap_or_else(|| self.directory.clone());
+        Finding::new(
+            FindingKind::MissingEcosystem,
+            file,
+            format!(
+                "`{}` dependencies in `{}` are not updated by Dependabot",
+                self.ecosystem, self.directory
+            ),
+        )
+        .with_dependency(&self.ecosystem, &self.directory)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReportSummary {
+    pub total_ecosystems: usize,
+    pub configured_ecosystems: usize,
+    pub missing_ecosystems: usize,
+}
+
+impl ReportSummary {
+    /// Percentage of the ecosystems of the project that are updated
+    /// automatically, rounded down to two decimals so that it compares
+    /// equal across runs. 100 for a project without dependencies.
+    pub fn coverage(&self) -> f64 {
+        if self.total_ecosystems == 0 {
+            return 100.0;
+        }
+        let coverage = self.configured_ecosystems as f64 * 100.0 / self.total_ecosystems as f64;
+        (coverage * 100.0).floor() / 100.0
+    }
+}
+
+/// An update tool whose configuration counts as covering dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Provider {
+    /// `.github/dependabot.yml`, which is also validated.
+    Dependabot,
+    /// Renovate configuration such as `renovate.json`.
+    Renovate,
+}
+
+impl Provider {
+    pub const ALL: &'static [Provider] = &[Provider::Dependabot, Provider::Renovate];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::Dependabot => "dependabot",
+            Provider::Renovate => "renovate",
+        }
+    }
+}
+
+impl FromStr for Provider {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Provider::ALL
+            .iter()
+            .copied()
+            .find(|provider| provider.name() == s)
+            .ok_or_else(|| Error::UnknownValue {
+                kind: "provider",
+                value: s.to_string(),
+                expected: Provider::ALL.iter().map(Provider::name).collect(),
+            })
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Analyzes a project for dependencies that are not updated automatically.
+#[derive(Debug, Clone)]
+pub struct Analyzer {
+    root: PathBuf,
+    excludes: Vec<String>,
+    providers: Vec<Provider>,
+    detectors: Vec<Arc<dyn Detector>>,
+}
+
+impl Analyzer {
+    /// An analyzer for the project at `root` that checks Dependabot.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Analyzer {
+            root: root.into(),
+            excludes: Vec::new(),
+            providers: vec![Provider::Dependabot],
+            detectors: detect::builtin(),
+        }
+    }
+
+    /// Skips files and directories matching a glob relative to the root,
+    /// such as `vendor/**` or `**/testdata`.
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.excludes.push(pattern.into());
+        self
+    }
+
+    /// Replaces the update tools whose configuration covers dependencies.
+    pub fn providers(mut self, providers: impl IntoIterator<Item = Provider>) -> Self {
+        self.providers = providers.into_iter().collect();
+        self
+    }
+
+    /// Adds a detector for ecosystems the built-in detectors do not
+    /// recognize, such as those of an internal build system.
+    pub fn detector(mut self, detector: impl Detector + 'static) -> Self {
+        self.detectors.push(Arc::new(detector));
+        self
+    }
+
+    /// Also applies the detection rules of the `.up2date.toml` of the root.
+    pub fn analyze(&self) -> Result<DependencyReport, Error> {
+        if !self.root.is_dir() {
+            return Err(Error::NotADirectory(self.root.clone()));
+        }
+
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &self.excludes {
+            let glob = GlobBuilder::new(pattern.trim_end_matches('/'))
+                .literal_separator(true)
+                .build()
+                .map_err(|err| Error::InvalidPattern {
+                    pattern: pattern.clone(),
+                    message: err.kind().to_string(),
+                })?;
+            builder.add(glob);
+        }
+        let excludes = builder.build().map_err(|err| Error::InvalidPattern {
+            pattern: self.excludes.join(", "),
+            message: err.to_string(),
+        })?;
+
+        let mut detectors = self.detectors.clone();
+        if let Some(config) = ProjectConfig::load(&self.root)? {
+            detectors.extend(config.detectors()?);
+        }
+
+        Ok(self.analyze_with(&excludes, &detectors))
+    }
+
+    fn analyze_with(
+        &self,
+        excludes: &GlobSet,
+        detectors: &[Arc<dyn Detector>],
+    ) -> DependencyReport {
+        let project_root = self.root.as_path();
+        let mut findings = Vec::new();
+        let project_dependencies =
+            find_project_dependencies(project_root, excludes, detectors, &mut findings);
+
+        let mut configured_ecosystems = Vec::new();
+
+        let project_ecosystem_set: HashSet<Ecosystem> = project_dependencies
+            .iter()
+            .map(|dep| dep.ecosystem.clone())
+            .collect();
+
+        if self.providers.contains(&Provider::Dependabot) {
+            findings.extend(find_legacy_dependabot_config(project_root));
+
+            let dependabot = find_dependabot_config(project_root, &mut findings);
+            if let Some(dependabot) = &dependabot {
+                configured_ecosystems.extend(find_dependabot_ecosystems(dependabot));
+                findings.extend(validate::check_ecosystem_names(dependabot));
+                findings.extend(validate::check_update_entries(dependabot));
+                findings.extend(validate::check_stale_entries(
+                    dependabot,
+                    &project_ecosystem_set,
+                ));
+            }
+        }
+
+        if self.providers.contains(&Provider::Renovate) {
+            for ecosystem in
+                find_renovate_ecosystems(project_root, &project_dependencies, &mut findings)
+            {
+                if !configured_ecosystems.contains(&ecosystem) {
+                    configured_ecosystems.push(ecosystem);
+                }
+            }
+        }
+
+        let configured_ecosystem_set: HashSet<Ecosystem> =
+            configured_ecosystems.iter().cloned().collect();
+
+        let missing_from_dependabot: Vec<Ecosystem> = project_ecosystem_set
+            .difference(&configured_ecosystem_set)
+            .cloned()
+            .collect();
+
+        let total_ecosystems = project_ecosystem_set.len();
+        let missing_ecosystems_count = missing_from_dependabot.len();
+
+        let mut report = DependencyReport {
+            project_dependencies,
+            dependabot_ecosystems: configured_ecosystems,
+            missing_from_dependabot,
+            summary: ReportSummary {
+                total_ecosystems,
+                configured_ecosystems: total_ecosystems - missing_ecosystems_count,
+                missing_ecosystems: missing_ecosystems_count,
+            },
+            findings,
+            suppressed: Vec::new(),
+        };
+        suppression::apply_inline_suppressions(project_root, &mut report);
+        report
+    }
+}
+
+/// Analyzes the project at `project_root` against its Dependabot
+/// configuration, with the defaults of [`Analyzer`].
+pub fn analyze_dependencies(project_root: impl AsRef<Path>) -> Result<DependencyReport, Error> {
+    Analyzer::new(project_root.as_ref()).analyze()
+}
+
+/// Runs the detectors on every file of the project, skipping paths matched
+/// by `excludes`. Paths that cannot be read are reported as findings.
+pub(crate) fn find_project_dependencies(
+    project_root: &Path,
+    excludes: &GlobSet,
+    detectors: &[Arc<dyn Detector>],
+    findings: &mut Vec<Finding>,
+) -> Vec<ProjectDependency> {
+    let mut ecosystem_dirs: HashMap<(Ecosystem, String), Vec<String>> = HashMap::new();
+
+    for entry in WalkDir::new(project_root)
+        .into_iter()
+        .filter_entry(|e| {
+            e.path()
+                .strip_prefix(project_root)
+                .map(|relative| relative.as_os_str().is_empty() || !excludes.is_match(relative))
+                .unwrap_or(true)
+        })
+        .filter_map(|e| match e {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                findings.push(unreadable_path(project_root, &err));
+                None
+            }
+        })
+        .filter(|e| e.file_type().is_file())
+    {
+        let Ok(relative) = entry.path().strip_prefix(project_root) else {
+            continue;
+        };
+        let file = ProjectFile::new(project_root, relative);
+
+        for detector in detectors {
+            for detection in detector.detect(&file) {
+                ecosystem_dirs
+                    .entry((detection.ecosystem, detection.directory))
+                    .or_default()
+                    .push(detection.evidence);
+            }
+        }
+    }
+
+    ecosystem_dirs
+        .into_iter()
+        .map(|((ecosystem, directory), mut manifests)| {
+            manifests.sort();
+            manifests.dedup();
+            ProjectDependency::new(ecosystem, directory, manifests)
+        })
+        .collect()
+}
+
+fn unreadable_path(project_root: &Path, err: &walkdir::Error) -> Finding {
+    let path = match err
+        .path()
+        .map(|path| path.strip_prefix(project_root).unwrap_or(path))
+    {
+        Some(path) if !path.as_os_str().is_empty() => path.to_string_lossy().to_string(),
+        _ => ".".to_string(),
+    };
+    let reason = match err.io_error() {
+        Some(io_error) => io_error.to_string(),
+        None => err.to_string(),
+    };
+
+    Finding::new(
+        FindingKind::UnreadablePath,
+        path.clone(),
+        format!(
+            "`{}` cannot be read, so dependencies in it are not checked: {}",
+            path, reason
+        ),
+    )
+}
+
+/// Reads the Dependabot configuration the way GitHub does: a single file,
+/// without falling back to the other location when it is invalid.
+fn find_dependabot_config(
+    project_root: &Path,
+    findings: &mut Vec<Finding>,
+) -> Option<DependabotFile> {
+    let existing: Vec<&'static str> = dependabot::CONFIG_PATHS
+        .into_iter()
+        .filter(|path| project_root.join(path).exists())
+        .collect();
+
+    let path = *existing.first()?;
+    for duplicate in &existing[1..] {
+        findings.push(Finding::new(
+            FindingKind::DuplicateConfigFile,
+            *duplicate,
+            format!(
+                "both `{}` and `{}` exist and it is ambiguous which one GitHub uses; \
+                 remove one of them",
+                path, duplicate
+            ),
+        ));
+    }
+
+    let content = match fs::read_to_string(project_root.join(path)) {
+        Ok(content) => content,
+        Err(err) => {
+            findings.push(Finding::new(
+                FindingKind::InvalidConfig,
+                path,
+                format!("failed to read the Dependabot configuration: {}", err),
+            ));
+            return None;
+        }
+    };
+
+    match DependabotFile::parse(path, &content) {
+        Ok(file) => Some(file),
+        Err(err) => {
+            findings.push(
+                Finding::new(
+                    FindingKind::InvalidConfig,
+                    path,
+                    format!("invalid Dependabot configuration: {}", err),
+                )
+                .with_line(err.location().map(|location| location.line())),
+            );
+            None
+        }
+    }
+}
+
+fn find_dependabot_ecosystems(dependabot: &DependabotFile) -> Vec<Ecosystem> {
+    dependabot
+        .config
+        .updates
+        .iter()
+        .filter(|update| update.package_ecosystem.is_known())
+        .map(|update| update.package_ecosystem.clone())
+        .collect()
+}
+
+/// GitHub stopped honouring Dependabot Preview configuration, so a leftover
+/// `.dependabot/config.yml` means updates the maintainers expect never happen.
+fn find_legacy_dependabot_config(project_root: &Path) -> Option<Finding> {
+    if !project_root.join(dependabot::LEGACY_CONFIG_PATH).is_file() {
+        return None;
+    }
+
+    Some(Finding::new(
+        FindingKind::LegacyConfig,
+        dependabot::LEGACY_CONFIG_PATH,
+        "Dependabot Preview configuration is no longer honoured by GitHub; \
+         migrate it with `up2date convert --from dependabot-v1 --to dependabot`",
+    ))
+}
+
+/// Renovate discovers package files by itself, so it covers an ecosystem
+/// whenever it would get a Dependabot entry when converting the
+/// configuration.
+fn find_renovate_ecosystems(
+    project_root: &Path,
+    dependencies: &[ProjectDependency],
+    findings: &mut Vec<Finding>,
+) -> Vec<Ecosystem> {
+    let config = match convert::load_renovate_config(project_root) {
+        Ok((_, config)) => config,
+        Err(Error::NoConfig(_)) => return Vec::new(),
+        Err(err) => {
+            let (file, message) = match err {
+                Error::Parse { path, message } => {
+                    (path, format!("invalid Renovate configuration: {}", message))
+                }
+                Error::Io { path, source } => (
+                    path,
+                    format!("failed to read the Renovate configuration: {}", source),
+                ),
+                Error::Json5Unsupported(path) => (
+                    path,
+                    "JSON5 Renovate configuration is not supported, so the ecosystems it \
+                     covers are unknown"
+                        .to_string(),
+                ),
+                err => (String::new(), err.to_string()),
+            };
+            findings.push(Finding::new(FindingKind::InvalidConfig, file, message));
+            return Vec::new();
+        }
+    };
+    if config.enabled == Some(false) {
+        return Vec::new();
+    }
+
+    convert::renovate_to_dependabot(&config, dependencies)
+        .config
+        .updates
+        .into_iter()
+        .map(|update| update.package_ecosystem)
+        .collect()
+}
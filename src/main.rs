@@ -2,304 +2,633 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use clap::Parser;
-use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser};
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use std::fmt;
 use std::fs;
-use std::path::Path;
-use walkdir::WalkDir;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use up2date::baseline::Baseline;
+use up2date::convert;
+use up2date::diff::{self, ReportDiff};
+use up2date::finding::{FindingKind, Severity};
+use up2date::output::{self, OutputFormat};
+use up2date::ratchet::Ratchet;
+use up2date::{Analyzer, DependencyReport, Provider};
+
+/// Some dependencies are not updated automatically.
+const EXIT_MISSING: i32 = 1;
+/// The command line is invalid; clap uses the same status.
+const EXIT_USAGE: i32 = 2;
+/// The check could not run or its output could not be written.
+const EXIT_ERROR: i32 = 3;
+/// The update configuration has errors.
+const EXIT_INVALID: i32 = 4;
+/// The update configuration has entries for ecosystems the project does not
+/// use.
+const EXIT_STALE: i32 = 5;
+/// The report has other warnings.
+const EXIT_WARNING: i32 = 6;
+
+const EXIT_STATUS_HELP: &str = "\
+Exit status:
+  0  passed, or only problems not selected by --fail-on were found
+  1  some dependencies are not updated automatically
+  2  invalid command line
+  3  the check could not run or its output could not be written
+  4  the update configuration has errors
+  5  the update configuration has stale entries
+  6  the report has warnings
+When several problems are selected by --fail-on, the first of 4, 1, 5 and 6 is used.";
 
 #[derive(Parser, Debug)]
 #[command(name = "up2date")]
 #[command(
     about = "Check if all dependencies in the current repository have been configured for automatic updates via dependabot"
 )]
+#[command(after_help = EXIT_STATUS_HELP)]
+#[command(group(
+    ArgGroup::new("stdout_format")
+        .args(["format", "json", "yaml", "toml", "sarif", "junit", "template"])
+))]
 struct Args {
-    /// Output in JSON format
-    #[arg(long)]
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Format of the report printed to stdout [default: github when
+    /// GITHUB_ACTIONS=true, markdown otherwise]
+    #[arg(long, value_parser = format_parser())]
+    format: Option<OutputFormat>,
+
+    /// Also write the report in FORMAT to PATH; can be repeated
+    #[arg(long, value_name = "FORMAT=PATH", value_parser = parse_output)]
+    output: Vec<Output>,
+
+    /// Alias for `--format json`
+    #[arg(long, hide = true)]
     json: bool,
 
-    /// Output in YAML format
-    #[arg(long)]
+    /// Alias for `--format yaml`
+    #[arg(long, hide = true)]
     yaml: bool,
 
-    /// Output in TOML format
-    #[arg(long)]
+    /// Alias for `--format toml`
+    #[arg(long, hide = true)]
     toml: bool,
+
+    /// Alias for `--format sarif`
+    #[arg(long, hide = true)]
+    sarif: bool,
+
+    /// Alias for `--format junit`
+    #[arg(long, hide = true)]
+    junit: bool,
+
+    /// Also write an SVG coverage badge to this path
+    #[arg(long, value_name = "PATH")]
+    badge: Option<PathBuf>,
+
+    /// Render the report printed to stdout with this Jinja template, which
+    /// receives the report as serialized by `--format json`
+    #[arg(long, value_name = "PATH")]
+    template: Option<PathBuf>,
+
+    /// Skip files and directories matching this glob, relative to the
+    /// project root; can be repeated
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Update tool whose configuration covers dependencies; can be repeated
+    /// [default: dependabot]
+    #[arg(long, value_parser = provider_parser())]
+    provider: Vec<Provider>,
+
+    /// Problems that make the check fail, separated by commas or repeated
+    /// [default: missing,invalid]
+    #[arg(long, value_enum, value_delimiter = ',')]
+    fail_on: Vec<FailOn>,
+
+    /// Exit successfully whatever problems are found; errors that stop the
+    /// check still fail
+    #[arg(long, conflicts_with = "fail_on")]
+    no_fail: bool,
+
+    /// Only fail on missing dependencies when less than this percentage of
+    /// the ecosystems is updated automatically
+    #[arg(long, value_name = "PERCENT", value_parser = parse_percent)]
+    min_coverage: Option<f64>,
+
+    /// Fail when the coverage drops below the one stored in this file, and
+    /// store the coverage whenever it improves
+    #[arg(long, value_name = "PATH")]
+    ratchet: Option<PathBuf>,
+
+    /// Do not report the findings listed in this file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Write every current finding to the `--baseline` file, keeping the
+    /// expiry dates and reasons of existing entries
+    #[arg(long, requires = "baseline")]
+    update_baseline: bool,
 }
 
 impl Args {
     fn output_format(&self) -> OutputFormat {
-        if self.json {
-            OutputFormat::Json
+        if let Some(format) = self.selected_format() {
+            format
+        } else if std::env::var("GITHUB_ACTIONS").is_ok_and(|value| value == "true") {
+            OutputFormat::Github
+        } else {
+            OutputFormat::Markdown
+        }
+    }
+
+    /// The options given that only apply to the check itself, which the
+    /// subcommands do not run.
+    fn check_options(&self) -> Vec<&'static str> {
+        [
+            ("--output", !self.output.is_empty()),
+            ("--badge", self.badge.is_some()),
+            ("--template", self.template.is_some()),
+            ("--exclude", !self.exclude.is_empty()),
+            ("--provider", !self.provider.is_empty()),
+            ("--fail-on", !self.fail_on.is_empty()),
+            ("--no-fail", self.no_fail),
+            ("--min-coverage", self.min_coverage.is_some()),
+            ("--ratchet", self.ratchet.is_some()),
+            ("--baseline", self.baseline.is_some()),
+            ("--update-baseline", self.update_baseline),
+        ]
+        .into_iter()
+        .filter(|(_, given)| *given)
+        .map(|(option, _)| option)
+        .collect()
+    }
+
+    /// The format chosen with `--format` or one of its aliases.
+    fn selected_format(&self) -> Option<OutputFormat> {
+        if let Some(format) = self.format {
+            Some(format)
+        } else if self.json {
+            Some(OutputFormat::Json)
         } else if self.yaml {
-            OutputFormat::Yaml
+            Some(OutputFormat::Yaml)
         } else if self.toml {
-            OutputFormat::Toml
+            Some(OutputFormat::Toml)
+        } else if self.sarif {
+            Some(OutputFormat::Sarif)
+        } else if self.junit {
+            Some(OutputFormat::Junit)
         } else {
-            OutputFormat::Markdown
+            None
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct DependabotConfig {
-    version: u8,
-    updates: Vec<UpdateConfig>,
+fn format_parser() -> impl TypedValueParser<Value = OutputFormat> {
+    PossibleValuesParser::new(
+        OutputFormat::ALL
+            .iter()
+            .map(|format| PossibleValue::new(format.name()).help(format.description())),
+    )
+    .try_map(|name| name.parse::<OutputFormat>())
+}
+
+fn provider_parser() -> impl TypedValueParser<Value = Provider> {
+    PossibleValuesParser::new(Provider::ALL.iter().map(Provider::name))
+        .try_map(|name| name.parse::<Provider>())
+}
+
+/// A report written to a file in addition to the one printed to stdout.
+#[derive(Clone, Debug)]
+struct Output {
+    format: OutputFormat,
+    path: PathBuf,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct UpdateConfig {
-    #[serde(rename = "package-ecosystem")]
-    package_ecosystem: String,
-    directory: String,
-    schedule: ScheduleConfig,
+fn parse_output(value: &str) -> Result<Output, String> {
+    let (format, path) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FORMAT=PATH, got `{}`", value))?;
+    if path.is_empty() {
+        return Err(format!("missing path for format `{}`", format));
+    }
+
+    Ok(Output {
+        format: format.parse().map_err(|e: up2date::Error| e.to_string())?,
+        path: PathBuf::from(path),
+    })
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ScheduleConfig {
-    interval: String,
+/// A kind of problem `--fail-on` can select, in the order their exit
+/// statuses take precedence.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum FailOn {
+    /// The update configuration cannot be used as is
+    Invalid,
+    /// Dependencies are not updated automatically
+    Missing,
+    /// Update entries for ecosystems the project does not use
+    Stale,
+    /// Any warning, including stale entries
+    Warning,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct DependencyReport {
-    project_dependencies: Vec<ProjectDependency>,
-    dependabot_ecosystems: Vec<String>,
-    missing_from_dependabot: Vec<String>,
-    summary: ReportSummary,
+impl FailOn {
+    const PRECEDENCE: [FailOn; 4] = [
+        FailOn::Invalid,
+        FailOn::Missing,
+        FailOn::Stale,
+        FailOn::Warning,
+    ];
+
+    /// Whether `report` has this problem. With a `min_coverage`, missing
+    /// dependencies are only a problem below it.
+    fn applies_to(&self, report: &DependencyReport, min_coverage: Option<f64>) -> bool {
+        match self {
+            FailOn::Invalid => report.has_blocking_findings(),
+            FailOn::Missing => match min_coverage {
+                Some(min_coverage) => report.summary.coverage() < min_coverage,
+                None => !report.coverage_findings().is_empty(),
+            },
+            FailOn::Stale => report
+                .findings
+                .iter()
+                .any(|finding| finding.kind == FindingKind::StaleEntry),
+            FailOn::Warning => report
+                .findings
+                .iter()
+                .any(|finding| finding.severity == Severity::Warning),
+        }
+    }
+
+    fn exit_code(&self) -> i32 {
+        match self {
+            FailOn::Invalid => EXIT_INVALID,
+            FailOn::Missing => EXIT_MISSING,
+            FailOn::Stale => EXIT_STALE,
+            FailOn::Warning => EXIT_WARNING,
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ProjectDependency {
-    ecosystem: String,
-    directory: String,
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Convert between Renovate and Dependabot configuration
+    Convert {
+        /// Configuration format to convert to
+        #[arg(long, value_enum)]
+        to: ConvertTarget,
+
+        /// Configuration format to convert from [default: renovate for
+        /// `--to dependabot` (dependabot-v1 if only that exists), dependabot
+        /// for `--to renovate`]
+        #[arg(long, value_enum)]
+        from: Option<ConvertSource>,
+    },
+    /// Compare two reports saved with `--format json`, `yaml` or `toml`
+    Diff {
+        /// The earlier report
+        old: PathBuf,
+
+        /// The later report
+        new: PathBuf,
+
+        /// Format of the differences printed to stdout; sarif, junit and
+        /// gitlab-codequality only show what got worse [default: markdown]
+        #[arg(long, value_parser = format_parser())]
+        format: Option<OutputFormat>,
+    },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ReportSummary {
-    total_ecosystems: usize,
-    configured_ecosystems: usize,
-    missing_ecosystems: usize,
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ConvertSource {
+    /// Renovate configuration such as `renovate.json`
+    Renovate,
+    /// `.github/dependabot.yml`
+    Dependabot,
+    /// Dependabot Preview `.dependabot/config.yml`
+    DependabotV1,
 }
 
-#[derive(Debug)]
-enum OutputFormat {
-    Markdown,
-    Json,
-    Yaml,
-    Toml,
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ConvertTarget {
+    /// Read the Renovate configuration and print `.github/dependabot.yml`
+    Dependabot,
+    /// Read `.github/dependabot.yml` and print `renovate.json`
+    Renovate,
 }
 
 fn main() {
     let args = Args::parse();
     let output_format = args.output_format();
 
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
-    let report = analyze_dependencies(&current_dir);
+    let current_dir = std::env::current_dir()
+        .unwrap_or_else(|e| fatal(format!("failed to get the current directory: {}", e)));
 
-    match output_format {
-        OutputFormat::Markdown => print_markdown_report(&report),
-        OutputFormat::Json => print_json_report(&report),
-        OutputFormat::Yaml => print_yaml_report(&report),
-        OutputFormat::Toml => print_toml_report(&report),
+    match args.command {
+        Some(Command::Convert { to, from }) => {
+            if let Some(option) = args.check_options().first() {
+                usage_error(format!("`{}` cannot be used with `convert`", option));
+            }
+            if let Some(format) = args.selected_format() {
+                usage_error(format!(
+                    "`--format {}` cannot be used with `convert`, which prints the \
+                     converted configuration",
+                    format
+                ));
+            }
+            run_convert(&current_dir, from, to);
+            return;
+        }
+        Some(Command::Diff {
+            ref old,
+            ref new,
+            format,
+        }) => {
+            if let Some(option) = args.check_options().first() {
+                usage_error(format!("`{}` cannot be used with `diff`", option));
+            }
+            let format = match (args.selected_format(), format) {
+                (Some(selected), Some(format)) if selected != format => usage_error(format!(
+                    "`--format {}` before `diff` conflicts with `diff --format {}`",
+                    selected, format
+                )),
+                (selected, format) => format.or(selected).unwrap_or(OutputFormat::Markdown),
+            };
+            run_diff(old, new, format);
+            return;
+        }
+        None => {}
     }
 
-    // Exit with code 1 if there are missing ecosystems
-    if !report.missing_from_dependabot.is_empty() {
-        std::process::exit(1);
+    let mut analyzer = Analyzer::new(&current_dir);
+    for pattern in &args.exclude {
+        analyzer = analyzer.exclude(pattern);
+    }
+    if !args.provider.is_empty() {
+        analyzer = analyzer.providers(args.provider.iter().copied());
+    }
+    let mut report = analyzer.analyze().unwrap_or_else(|e| match e {
+        up2date::Error::InvalidPattern { .. } => usage_error(e),
+        e => fatal(e),
+    });
+    if let Some(path) = &args.baseline {
+        apply_baseline(path, args.update_baseline, &mut report);
     }
-}
-
-fn analyze_dependencies(project_root: &Path) -> DependencyReport {
-    let project_dependencies = find_project_dependencies(project_root);
-    let dependabot_ecosystems = find_dependabot_ecosystems(project_root);
-
-    let project_ecosystem_set: HashSet<String> = project_dependencies
-        .iter()
-        .map(|dep| dep.ecosystem.clone())
-        .collect();
-
-    let dependabot_ecosystem_set: HashSet<String> = dependabot_ecosystems.iter().cloned().collect();
 
-    let missing_from_dependabot: Vec<String> = project_ecosystem_set
-        .difference(&dependabot_ecosystem_set)
-        .cloned()
-        .collect();
+    if let Some(path) = &args.badge {
+        if let Err(e) = fs::write(path, output::render_badge(&report.summary)) {
+            fatal(format!(
+                "failed to write badge to {}: {}",
+                path.display(),
+                e
+            ));
+        }
+    }
 
-    let total_ecosystems = project_ecosystem_set.len();
-    let configured_ecosystems = total_ecosystems - missing_from_dependabot.len();
+    for output in &args.output {
+        let content =
+            output::render(output.format, &report, &current_dir).unwrap_or_else(|e| fatal(e));
+        if let Err(e) = fs::write(&output.path, content) {
+            fatal(format!(
+                "failed to write {} report to {}: {}",
+                output.format,
+                output.path.display(),
+                e
+            ));
+        }
+    }
 
-    let missing_ecosystems_count = missing_from_dependabot.len();
+    let mut formats: Vec<OutputFormat> = args.output.iter().map(|output| output.format).collect();
+    if let Some(path) = &args.template {
+        print_template_report(path, &report);
+    } else {
+        let content =
+            output::render(output_format, &report, &current_dir).unwrap_or_else(|e| fatal(e));
+        write_stdout(&content);
+        formats.push(output_format);
+    }
 
-    DependencyReport {
-        project_dependencies,
-        dependabot_ecosystems,
-        missing_from_dependabot,
-        summary: ReportSummary {
-            total_ecosystems,
-            configured_ecosystems,
-            missing_ecosystems: missing_ecosystems_count,
-        },
+    if formats.contains(&OutputFormat::Github) {
+        if let Err(e) = output::append_github_step_summary(&report) {
+            eprintln!("warning: failed to write job summary: {}", e);
+        }
     }
-}
 
-fn find_project_dependencies(project_root: &Path) -> Vec<ProjectDependency> {
-    let mut dependencies = Vec::new();
-    let mut ecosystem_dirs: HashMap<String, String> = HashMap::new();
-    let mut has_github_workflows = false;
-
-    // Check for GitHub Actions workflows in .github/workflows (root only)
-    let workflows_dir = project_root.join(".github/workflows");
-    if workflows_dir.exists() && workflows_dir.is_dir() {
-        if let Ok(entries) = std::fs::read_dir(&workflows_dir) {
-            for entry in entries.flatten() {
-                if let Some(file_name) = entry.file_name().to_str() {
-                    if file_name.ends_with(".yml") || file_name.ends_with(".yaml") {
-                        has_github_workflows = true;
-                        break;
-                    }
-                }
-            }
+    let fail_on = if args.fail_on.is_empty() {
+        vec![FailOn::Missing, FailOn::Invalid]
+    } else {
+        args.fail_on.clone()
+    };
+    let min_coverage = required_coverage(&args, &report);
+    if let Some(min_coverage) = min_coverage {
+        let coverage = report.summary.coverage();
+        if coverage < min_coverage && !args.no_fail {
+            eprintln!(
+                "coverage {}% is below the required {}%",
+                coverage, min_coverage
+            );
         }
     }
 
-    // Recursively check for other dependency files
-    for entry in WalkDir::new(project_root)
+    if args.no_fail {
+        return;
+    }
+    // A required coverage always fails the check when it is not reached,
+    // whichever problems are selected.
+    let selected = |problem: &FailOn| {
+        fail_on.contains(problem) || (*problem == FailOn::Missing && min_coverage.is_some())
+    };
+    if let Some(failure) = FailOn::PRECEDENCE
         .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
+        .find(|problem| selected(problem) && problem.applies_to(&report, min_coverage))
     {
-        let path = entry.path();
-        let file_name = path.file_name().unwrap().to_string_lossy();
-
-        let ecosystem = match file_name.as_ref() {
-            "Cargo.toml" => Some("cargo"),
-            "package.json" => Some("npm"),
-            "requirements.txt" | "pyproject.toml" | "setup.py" | "Pipfile" => Some("pip"),
-            "go.mod" => Some("gomod"),
-            ".gitmodules" => Some("gitsubmodule"),
-            "Dockerfile" | "Containerfile" => Some("docker"),
-            "action.yaml" | "action.yml" => Some("github-actions"),
-            _ => None,
-        };
+        std::process::exit(failure.exit_code());
+    }
+}
 
-        if let Some(ecosystem) = ecosystem {
-            let relative_dir = path
-                .parent()
-                .unwrap()
-                .strip_prefix(project_root)
-                .unwrap_or(Path::new("."))
-                .to_string_lossy()
-                .to_string();
+/// Suppresses the findings listed in the baseline at `path`, first
+/// rewriting it from `report` when `update` is set.
+fn apply_baseline(path: &Path, update: bool, report: &mut DependencyReport) {
+    let mut baseline = if update && !path.exists() {
+        None
+    } else {
+        Some(Baseline::load(path).unwrap_or_else(|e| fatal(e)))
+    };
+    if update {
+        let updated = Baseline::from_report(report, baseline.as_ref());
+        updated.save(path).unwrap_or_else(|e| fatal(e));
+        eprintln!(
+            "recorded {} findings in {}",
+            updated.entries.len(),
+            path.display()
+        );
+        baseline = Some(updated);
+    }
 
-            let dir_key = format!("{}:{}", ecosystem, relative_dir);
+    let source = path.display().to_string();
+    for entry in baseline.unwrap().apply(report, &source) {
+        let target = match (&entry.ecosystem, &entry.directory, &entry.file) {
+            (Some(ecosystem), Some(directory), _) => format!("{} in `{}`", ecosystem, directory),
+            (Some(ecosystem), None, _) => ecosystem.to_string(),
+            (None, _, Some(file)) => format!("`{}`", file),
+            (None, _, None) => "every file".to_string(),
+        };
+        eprintln!(
+            "warning: baseline entry for {} of {} expired on {}",
+            entry.kind,
+            target,
+            entry.expires.as_deref().unwrap_or_default()
+        );
+    }
+}
 
-            if !ecosystem_dirs.contains_key(&dir_key) {
-                ecosystem_dirs.insert(dir_key, relative_dir);
+/// The coverage `--min-coverage` and `--ratchet` require, raising the
+/// ratchet when the coverage improved.
+fn required_coverage(args: &Args, report: &DependencyReport) -> Option<f64> {
+    let Some(path) = &args.ratchet else {
+        return args.min_coverage;
+    };
+
+    let coverage = report.summary.coverage();
+    let stored = Ratchet::load(path).unwrap_or_else(|e| fatal(e));
+    match stored {
+        Some(stored) if coverage <= stored.coverage => {}
+        _ => {
+            Ratchet::new(coverage)
+                .save(path)
+                .unwrap_or_else(|e| fatal(e));
+            if let Some(stored) = stored {
+                eprintln!(
+                    "coverage improved from {}% to {}%, updated {}",
+                    stored.coverage,
+                    coverage,
+                    path.display()
+                );
             }
         }
     }
 
-    // Add GitHub Actions workflows if found
-    if has_github_workflows {
-        dependencies.push(ProjectDependency {
-            ecosystem: "github-actions".to_string(),
-            directory: ".".to_string(),
-        });
-    }
+    let ratchet = stored.map_or(coverage, |stored| stored.coverage);
+    Some(args.min_coverage.map_or(ratchet, |min| min.max(ratchet)))
+}
 
-    for (ecosystem_dir, directory) in ecosystem_dirs {
-        let ecosystem = ecosystem_dir.split(':').next().unwrap().to_string();
-        dependencies.push(ProjectDependency {
-            ecosystem,
-            directory: if directory.is_empty() {
-                ".".to_string()
-            } else {
-                directory
-            },
-        });
+fn parse_percent(value: &str) -> Result<f64, String> {
+    let percent: f64 = value
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| format!("`{}` is not a number", value))?;
+    if !(0.0..=100.0).contains(&percent) {
+        return Err(format!("{} is not between 0 and 100", percent));
     }
-
-    dependencies
+    Ok(percent)
 }
 
-fn find_dependabot_ecosystems(project_root: &Path) -> Vec<String> {
-    let dependabot_paths = [".github/dependabot.yml", ".github/dependabot.yaml"];
-
-    for path in &dependabot_paths {
-        let full_path = project_root.join(path);
-        if !full_path.exists() {
-            continue;
-        }
-
-        let Ok(content) = fs::read_to_string(&full_path) else {
-            continue;
-        };
+/// Reports an invalid command line that clap cannot detect and exits.
+fn usage_error(message: impl fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(EXIT_USAGE);
+}
 
-        let Ok(config) = serde_yaml::from_str::<DependabotConfig>(&content) else {
-            continue;
-        };
+/// Reports an error that stops the check and exits.
+fn fatal(message: impl fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(EXIT_ERROR);
+}
 
-        return config
-            .updates
-            .into_iter()
-            .map(|update| update.package_ecosystem)
-            .collect();
+/// Prints to stdout. A closed pipe, such as `up2date | head`, only means
+/// the reader has seen enough.
+fn write_stdout(content: &str) {
+    let mut stdout = io::stdout().lock();
+    match stdout
+        .write_all(content.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => fatal(format!("failed to write to stdout: {}", e)),
     }
-
-    Vec::new()
 }
 
-fn print_markdown_report(report: &DependencyReport) {
-    println!("# Dependabot Coverage Report\n");
-
-    println!("## Summary\n");
-    println!(
-        "- **Total ecosystems found**: {}",
-        report.summary.total_ecosystems
-    );
-    println!(
-        "- **Configured in dependabot**: {}",
-        report.summary.configured_ecosystems
-    );
-    println!(
-        "- **Missing from dependabot**: {}\n",
-        report.summary.missing_ecosystems
-    );
-
-    println!("## Project Dependencies\n");
-    for dep in &report.project_dependencies {
-        println!("- **{}** in `{}`", dep.ecosystem, dep.directory);
-    }
-    println!();
+fn run_convert(project_root: &Path, from: Option<ConvertSource>, to: ConvertTarget) {
+    let from = from.unwrap_or(match to {
+        ConvertTarget::Dependabot
+            if up2date::renovate::find_config(project_root).is_none()
+                && project_root
+                    .join(up2date::dependabot::LEGACY_CONFIG_PATH)
+                    .is_file() =>
+        {
+            ConvertSource::DependabotV1
+        }
+        ConvertTarget::Dependabot => ConvertSource::Renovate,
+        ConvertTarget::Renovate => ConvertSource::Dependabot,
+    });
 
-    if !report.missing_from_dependabot.is_empty() {
-        println!("## Missing from Dependabot\n");
-        for ecosystem in &report.missing_from_dependabot {
-            println!("- {}", ecosystem);
+    let result = match (from, to) {
+        (ConvertSource::Renovate, ConvertTarget::Dependabot) => {
+            convert::convert_to_dependabot(project_root)
         }
-        println!();
-    }
+        (ConvertSource::DependabotV1, ConvertTarget::Dependabot) => {
+            convert::convert_legacy_to_dependabot(project_root)
+        }
+        (ConvertSource::Dependabot, ConvertTarget::Renovate) => {
+            convert::convert_to_renovate(project_root)
+        }
+        (ConvertSource::DependabotV1, ConvertTarget::Renovate) => {
+            convert::convert_legacy_to_renovate(project_root)
+        }
+        (ConvertSource::Renovate, ConvertTarget::Renovate)
+        | (ConvertSource::Dependabot, ConvertTarget::Dependabot) => {
+            usage_error("source and target configuration formats are the same");
+        }
+    };
 
-    if !report.dependabot_ecosystems.is_empty() {
-        println!("## Configured in Dependabot\n");
-        for ecosystem in &report.dependabot_ecosystems {
-            println!("- {}", ecosystem);
+    match result {
+        Ok(conversion) => {
+            for warning in &conversion.warnings {
+                eprintln!("warning: {}", warning);
+            }
+            write_stdout(&format!("{}\n", conversion.config.trim_end()));
         }
+        Err(err) => fatal(err),
     }
 }
 
-fn print_json_report(report: &DependencyReport) {
-    let json = serde_json::to_string_pretty(report).unwrap();
-    println!("{}", json);
+fn run_diff(old: &Path, new: &Path, format: OutputFormat) {
+    let old = diff::read_report(old).unwrap_or_else(|e| fatal(e));
+    let new = diff::read_report(new).unwrap_or_else(|e| fatal(e));
+    let report_diff = ReportDiff::new(&old, &new);
+
+    match output::render_diff(format, &report_diff) {
+        Ok(rendered) => {
+            if output::diff_shows_only_regressions(format) {
+                if let Some(improvements) = report_diff.improvements() {
+                    eprintln!(
+                        "warning: {} only shows what got worse, leaving out {}",
+                        format, improvements
+                    );
+                }
+            }
+            write_stdout(&rendered);
+        }
+        Err(e @ up2date::Error::UnsupportedDiffFormat(_)) => usage_error(e),
+        Err(e) => fatal(e),
+    }
 }
 
-fn print_yaml_report(report: &DependencyReport) {
-    let yaml = serde_yaml::to_string(report).unwrap();
-    println!("{}", yaml);
-}
+fn print_template_report(path: &Path, report: &DependencyReport) {
+    let rendered = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read template {}: {}", path.display(), e))
+        .and_then(|source| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            output::render_template(&name, &source, report)
+                .map_err(|e| format!("failed to render template {}: {}", path.display(), e))
+        });
 
-fn print_toml_report(report: &DependencyReport) {
-    let toml_value = toml::to_string(report).unwrap();
-    println!("{}", toml_value);
+    match rendered {
+        Ok(rendered) => write_stdout(&rendered),
+        Err(e) => fatal(e),
+    }
 }
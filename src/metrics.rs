@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Prometheus text exposition and OpenMetrics output, for the node_exporter
+//! textfile collector.
+
+use crate::diff::ReportDiff;
+use crate::finding::FindingKind;
+use crate::{DependencyReport, ProjectDependency};
+use std::fmt::{Display, Write};
+
+pub fn prometheus_report(report: &DependencyReport) -> String {
+    metrics(report)
+}
+
+/// OpenMetrics is the Prometheus format with a mandatory `# EOF` trailer,
+/// as long as only gauges are exposed.
+pub fn openmetrics_report(report: &DependencyReport) -> String {
+    format!("{}# EOF\n", metrics(report))
+}
+
+pub fn prometheus_diff(diff: &ReportDiff) -> String {
+    diff_metrics(diff)
+}
+
+pub fn openmetrics_diff(diff: &ReportDiff) -> String {
+    format!("{}# EOF\n", diff_metrics(diff))
+}
+
+/// The changes between two reports as gauges, for alerting on a drop in
+/// coverage between two runs.
+fn diff_metrics(diff: &ReportDiff) -> String {
+    let mut out = String::new();
+    let summary = &diff.summary;
+
+    gauge(
+        &mut out,
+        "up2date_ecosystems_total_change",
+        "Change of the ecosystems found in the project",
+        &[(String::new(), summary.total_ecosystems)],
+    );
+    gauge(
+        &mut out,
+        "up2date_ecosystems_configured_change",
+        "Change of the ecosystems found in the project that Dependabot updates",
+        &[(String::new(), summary.configured_ecosystems)],
+    );
+    gauge(
+        &mut out,
+        "up2date_ecosystems_missing_change",
+        "Change of the ecosystems found in the project that Dependabot does not update",
+        &[(String::new(), summary.missing_ecosystems)],
+    );
+    gauge(
+        &mut out,
+        "up2date_coverage_change",
+        "Change of the coverage in percentage points",
+        &[(String::new(), summary.coverage)],
+    );
+    gauge(
+        &mut out,
+        "up2date_dependencies_newly_covered",
+        "Dependencies of an ecosystem in a directory that Dependabot updates now",
+        &[(String::new(), diff.covered.len())],
+    );
+    gauge(
+        &mut out,
+        "up2date_dependencies_newly_missing",
+        "Dependencies of an ecosystem in a directory that Dependabot no longer updates",
+        &[(String::new(), diff.missing.len())],
+    );
+    gauge(
+        &mut out,
+        "up2date_stale_entries_new",
+        "Stale entries of the update configuration that were not reported before",
+        &[(String::new(), diff.stale.len())],
+    );
+
+    out
+}
+
+fn metrics(report: &DependencyReport) -> String {
+    let mut out = String::new();
+
+    gauge(
+        &mut out,
+        "up2date_ecosystems_total",
+        "Ecosystems found in the project",
+        &[(String::new(), report.summary.total_ecosystems)],
+    );
+    gauge(
+        &mut out,
+        "up2date_ecosystems_configured",
+        "Ecosystems found in the project that Dependabot updates",
+        &[(String::new(), report.summary.configured_ecosystems)],
+    );
+    gauge(
+        &mut out,
+        "up2date_ecosystems_missing",
+        "Ecosystems found in the project that Dependabot does not update",
+        &[(String::new(), report.summary.missing_ecosystems)],
+    );
+
+    let mut dependencies: Vec<&ProjectDependency> = report.project_dependencies.iter().collect();
+    dependencies.sort_by(|a, b| (&a.ecosystem, &a.directory).cmp(&(&b.ecosystem, &b.directory)));
+    let covered: Vec<(String, usize)> = dependencies
+        .iter()
+        .map(|dep| {
+            (
+                labels(&[
+                    ("ecosystem", dep.ecosystem.name()),
+                    ("directory", &dep.directory),
+                ]),
+                usize::from(!report.missing_from_dependabot.contains(&dep.ecosystem)),
+            )
+        })
+        .collect();
+    gauge(
+        &mut out,
+        "up2date_dependency_covered",
+        "Whether Dependabot updates the dependencies of an ecosystem in a directory",
+        &covered,
+    );
+
+    // Every kind is exposed, so that fixed findings drop to zero instead of
+    // leaving a stale series behind.
+    let findings = report.all_findings();
+    let counts: Vec<(String, usize)> = FindingKind::ALL
+        .iter()
+        .map(|kind| {
+            (
+                labels(&[
+                    ("kind", kind.as_str()),
+                    ("severity", kind.severity().as_str()),
+                ]),
+                findings
+                    .iter()
+                    .filter(|finding| finding.kind == *kind)
+                    .count(),
+            )
+        })
+        .collect();
+    gauge(
+        &mut out,
+        "up2date_findings",
+        "Findings about the update configuration, by kind",
+        &counts,
+    );
+
+    out
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, samples: &[(String, impl Display)]) {
+    writeln!(out, "# HELP {} {}", name, help).unwrap();
+    writeln!(out, "# TYPE {} gauge", name).unwrap();
+    for (labels, value) in samples {
+        writeln!(out, "{}{} {}", name, labels, value).unwrap();
+    }
+}
+
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let pairs: Vec<String> = pairs
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape(value)))
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
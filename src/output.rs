@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Rendering of a [`DependencyReport`] in the supported formats.
+
+use crate::diff::ReportDiff;
+use crate::{badge, github, gitlab, html, junit, metrics, sarif, sbom, template};
+use crate::{DependencyReport, Error, ReportSummary};
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    Markdown,
+    Json,
+    Yaml,
+    Toml,
+    Sarif,
+    Junit,
+    Github,
+    GitlabCodequality,
+    Html,
+    Prometheus,
+    Openmetrics,
+    Cyclonedx,
+    Spdx,
+}
+
+impl OutputFormat {
+    pub const ALL: &'static [OutputFormat] = &[
+        OutputFormat::Markdown,
+        OutputFormat::Json,
+        OutputFormat::Yaml,
+        OutputFormat::Toml,
+        OutputFormat::Sarif,
+        OutputFormat::Junit,
+        OutputFormat::Github,
+        OutputFormat::GitlabCodequality,
+        OutputFormat::Html,
+        OutputFormat::Prometheus,
+        OutputFormat::Openmetrics,
+        OutputFormat::Cyclonedx,
+        OutputFormat::Spdx,
+    ];
+
+    /// The name of the format on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Json => "json",
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Toml => "toml",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Github => "github",
+            OutputFormat::GitlabCodequality => "gitlab-codequality",
+            OutputFormat::Html => "html",
+            OutputFormat::Prometheus => "prometheus",
+            OutputFormat::Openmetrics => "openmetrics",
+            OutputFormat::Cyclonedx => "cyclonedx",
+            OutputFormat::Spdx => "spdx",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "Human-readable Markdown report",
+            OutputFormat::Json => "JSON",
+            OutputFormat::Yaml => "YAML",
+            OutputFormat::Toml => "TOML",
+            OutputFormat::Sarif => "SARIF for GitHub code scanning",
+            OutputFormat::Junit => "JUnit XML for CI test dashboards",
+            OutputFormat::Github => {
+                "GitHub Actions annotations, plus a job summary when GITHUB_STEP_SUMMARY is set"
+            }
+            OutputFormat::GitlabCodequality => "GitLab Code Quality report",
+            OutputFormat::Html => "Self-contained HTML page with a coverage matrix",
+            OutputFormat::Prometheus => {
+                "Prometheus text format for the node_exporter textfile collector"
+            }
+            OutputFormat::Openmetrics => "OpenMetrics text format",
+            OutputFormat::Cyclonedx => "CycloneDX 1.5 JSON listing the detected manifests",
+            OutputFormat::Spdx => "SPDX 2.3 JSON listing the detected manifests",
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OutputFormat::ALL
+            .iter()
+            .copied()
+            .find(|format| format.name() == s)
+            .ok_or_else(|| Error::UnknownValue {
+                kind: "format",
+                value: s.to_string(),
+                expected: OutputFormat::ALL.iter().map(OutputFormat::name).collect(),
+            })
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Renders `report` in `format`. Formats that describe the files of the
+/// project, such as SPDX, read them from `project_root`.
+pub fn render(
+    format: OutputFormat,
+    report: &DependencyReport,
+    project_root: &Path,
+) -> Result<String, Error> {
+    match format {
+        OutputFormat::Markdown => markdown_report(report),
+        OutputFormat::Json => json_report(report),
+        OutputFormat::Yaml => yaml_report(report),
+        OutputFormat::Toml => toml_report(report),
+        OutputFormat::Sarif => sarif::sarif_report(report),
+        OutputFormat::Junit => Ok(junit::junit_report(report)),
+        OutputFormat::Github => Ok(github::annotations(report)),
+        OutputFormat::GitlabCodequality => gitlab::codequality_report(report),
+        OutputFormat::Html => html::html_report(report),
+        OutputFormat::Prometheus => Ok(metrics::prometheus_report(report)),
+        OutputFormat::Openmetrics => Ok(metrics::openmetrics_report(report)),
+        OutputFormat::Cyclonedx => sbom::cyclonedx_report(report, project_root),
+        OutputFormat::Spdx => sbom::spdx_report(report, project_root),
+    }
+}
+
+/// Renders `diff` in `format`. Formats that list findings, such as SARIF,
+/// show the [regressions](ReportDiff::regressions), and GitHub annotations
+/// add a notice with the rest. Metrics show the changes as gauges. SBOMs
+/// describe a single state of the project and cannot show a diff.
+pub fn render_diff(format: OutputFormat, diff: &ReportDiff) -> Result<String, Error> {
+    match format {
+        OutputFormat::Markdown => template::render("diff.md", template::DIFF_TEMPLATE, diff)
+            .map_err(|err| Error::render("markdown", err)),
+        OutputFormat::Json => json_report(diff),
+        OutputFormat::Yaml => yaml_report(diff),
+        OutputFormat::Toml => toml_report(diff),
+        OutputFormat::Sarif => sarif::sarif_report(&diff.regressions()),
+        OutputFormat::Junit => Ok(junit::junit_report(&diff.regressions())),
+        OutputFormat::Github => Ok(github::diff_annotations(diff)),
+        OutputFormat::GitlabCodequality => gitlab::codequality_report(&diff.regressions()),
+        OutputFormat::Html => Ok(html::html_diff(diff)),
+        OutputFormat::Prometheus => Ok(metrics::prometheus_diff(diff)),
+        OutputFormat::Openmetrics => Ok(metrics::openmetrics_diff(diff)),
+        OutputFormat::Cyclonedx | OutputFormat::Spdx => {
+            Err(Error::UnsupportedDiffFormat(format.name()))
+        }
+    }
+}
+
+/// Whether [`render_diff`] shows only the
+/// [regressions](ReportDiff::regressions) in `format`, leaving out what
+/// [`ReportDiff::improvements`] describes.
+pub fn diff_shows_only_regressions(format: OutputFormat) -> bool {
+    matches!(
+        format,
+        OutputFormat::Sarif | OutputFormat::Junit | OutputFormat::GitlabCodequality
+    )
+}
+
+/// Renders `report` with a Jinja template, which receives the report as
+/// serialized by [`OutputFormat::Json`] and `missing`, the missing
+/// ecosystems that are not suppressed. The `name` of the template decides
+/// auto-escaping the way Jinja does.
+pub fn render_template(
+    name: &str,
+    source: &str,
+    report: &DependencyReport,
+) -> Result<String, Error> {
+    template::render_report(name, source, report).map_err(Error::Template)
+}
+
+/// Renders a shields.io style SVG badge showing the coverage.
+pub fn render_badge(summary: &ReportSummary) -> String {
+    badge::render_badge(summary)
+}
+
+/// Appends the Markdown report to the GitHub Actions job summary, when
+/// `GITHUB_STEP_SUMMARY` is set.
+pub fn append_github_step_summary(report: &DependencyReport) -> Result<(), Error> {
+    github::append_step_summary(report)
+}
+
+pub(crate) fn markdown_report(report: &DependencyReport) -> Result<String, Error> {
+    template::render_report("report.md", template::DEFAULT_TEMPLATE, report)
+        .map_err(|err| Error::render("markdown", err))
+}
+
+fn json_report(report: &impl Serialize) -> Result<String, Error> {
+    let json = serde_json::to_string_pretty(report).map_err(|err| Error::render("json", err))?;
+    Ok(format!("{}\n", json))
+}
+
+fn yaml_report(report: &impl Serialize) -> Result<String, Error> {
+    let yaml = serde_yaml::to_string(report).map_err(|err| Error::render("yaml", err))?;
+    Ok(format!("{}\n", yaml))
+}
+
+fn toml_report(report: &impl Serialize) -> Result<String, Error> {
+    let toml_value = toml::to_string(report).map_err(|err| Error::render("toml", err))?;
+    Ok(format!("{}\n", toml_value))
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The coverage a project has reached, kept in a committed file so that it
+//! can only go up.
+
+use crate::Error;
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct Ratchet {
+    /// Minimum coverage in percent, as computed by
+    /// [`ReportSummary::coverage`](crate::ReportSummary::coverage).
+    pub coverage: f64,
+}
+
+impl Ratchet {
+    pub fn new(coverage: f64) -> Self {
+        Ratchet { coverage }
+    }
+
+    /// Reads the ratchet file at `path`, if it exists.
+    pub fn load(path: &Path) -> Result<Option<Ratchet>, Error> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+
+        let ratchet: Ratchet = toml::from_str(&content).map_err(|err| Error::Parse {
+            path: path.display().to_string(),
+            message: err.to_string().trim_end().to_string(),
+        })?;
+        if !(0.0..=100.0).contains(&ratchet.coverage) {
+            return Err(Error::Parse {
+                path: path.display().to_string(),
+                message: format!("coverage {} is not a percentage", ratchet.coverage),
+            });
+        }
+        Ok(Some(ratchet))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let content = format!(
+            "# Minimum coverage in percent, raised by `up2date --ratchet` when it improves.\n\
+             coverage = {:?}\n",
+            self.coverage
+        );
+        fs::write(path, content).map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::Ecosystem;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Locations Renovate reads the repository configuration from, in order.
+pub const CONFIG_PATHS: [&str; 9] = [
+    "renovate.json",
+    "renovate.json5",
+    ".github/renovate.json",
+    ".github/renovate.json5",
+    ".gitlab/renovate.json",
+    ".gitlab/renovate.json5",
+    ".renovaterc",
+    ".renovaterc.json",
+    ".renovaterc.json5",
+];
+
+/// The manifest whose `renovate` key Renovate reads when none of
+/// [`CONFIG_PATHS`] exists.
+pub const PACKAGE_JSON: &str = "package.json";
+
+/// The file Renovate reads the configuration of `project_root` from, if any.
+pub fn find_config(project_root: &Path) -> Option<&'static str> {
+    find_configs(project_root).into_iter().next()
+}
+
+/// Every configuration location of `project_root` that exists, in the order
+/// Renovate looks for them. Renovate only reads the first.
+pub fn find_configs(project_root: &Path) -> Vec<&'static str> {
+    let mut paths: Vec<&'static str> = CONFIG_PATHS
+        .into_iter()
+        .filter(|path| project_root.join(path).is_file())
+        .collect();
+
+    let manifest = fs::read_to_string(project_root.join(PACKAGE_JSON))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+    if manifest.is_some_and(|manifest| manifest.get("renovate").is_some()) {
+        paths.push(PACKAGE_JSON);
+    }
+
+    paths
+}
+
+pub const SCHEMA_URL: &str = "https://docs.renovatebot.com/renovate-schema.json";
+
+/// Renovate managers and the Dependabot ecosystem handling the same files.
+const MANAGERS: [(&str, Ecosystem); 24] = [
+    ("bun", Ecosystem::Bun),
+    ("bundler", Ecosystem::Bundler),
+    ("cargo", Ecosystem::Cargo),
+    ("composer", Ecosystem::Composer),
+    ("devcontainer", Ecosystem::Devcontainers),
+    ("docker-compose", Ecosystem::DockerCompose),
+    ("dockerfile", Ecosystem::Docker),
+    ("git-submodules", Ecosystem::Gitsubmodule),
+    ("github-actions", Ecosystem::GithubActions),
+    ("gomod", Ecosystem::Gomod),
+    ("gradle", Ecosystem::Gradle),
+    ("helmv3", Ecosystem::Helm),
+    ("maven", Ecosystem::Maven),
+    ("mix", Ecosystem::Mix),
+    ("npm", Ecosystem::Npm),
+    ("nuget", Ecosystem::Nuget),
+    ("pep621", Ecosystem::Pip),
+    ("pip_requirements", Ecosystem::Pip),
+    ("pip_setup", Ecosystem::Pip),
+    ("pipenv", Ecosystem::Pip),
+    ("poetry", Ecosystem::Pip),
+    ("pub", Ecosystem::Pub),
+    ("swift", Ecosystem::Swift),
+    ("terraform", Ecosystem::Terraform),
+];
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RenovateConfig {
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enabled_managers: Vec<String>,
+
+    #[serde(
+        default,
+        deserialize_with = "string_or_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub schedule: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore_deps: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore_paths: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_paths: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub base_branches: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_concurrent_limit: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub package_rules: Vec<PackageRule>,
+
+    /// Settings this tool does not model, including per-manager objects such
+    /// as `"git-submodules": { "enabled": true }`.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl RenovateConfig {
+    /// Whether a manager that Renovate disables by default has been turned on
+    /// through its per-manager `enabled` setting.
+    pub fn manager_explicitly_enabled(&self, manager: &str) -> bool {
+        self.enabled_managers.iter().any(|m| m == manager)
+            || self.manager_enabled_setting(manager) == Some(true)
+    }
+
+    /// Whether a manager has been turned off through its per-manager
+    /// object, such as `"npm": { "enabled": false }`.
+    pub fn manager_disabled(&self, manager: &str) -> bool {
+        self.manager_enabled_setting(manager) == Some(false)
+    }
+
+    /// Whether every manager handling the files of `ecosystem` has been
+    /// turned off, so that Renovate does not update it at all.
+    pub fn ecosystem_disabled(&self, ecosystem: &Ecosystem) -> bool {
+        let managers = managers_for_ecosystem(ecosystem);
+        !managers.is_empty()
+            && managers
+                .iter()
+                .all(|manager| self.manager_disabled(manager))
+    }
+
+    fn manager_enabled_setting(&self, manager: &str) -> Option<bool> {
+        self.other
+            .get(manager)
+            .and_then(|value| value.get("enabled"))
+            .and_then(serde_json::Value::as_bool)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PackageRule {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_managers: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_package_names: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_package_patterns: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_dep_types: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_update_types: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_file_names: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    #[serde(
+        default,
+        deserialize_with = "string_or_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub schedule: Vec<String>,
+
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+/// The Dependabot ecosystem covering the same files as a Renovate manager.
+pub fn ecosystem_for_manager(manager: &str) -> Option<Ecosystem> {
+    MANAGERS
+        .into_iter()
+        .find(|(name, _)| *name == manager)
+        .map(|(_, ecosystem)| ecosystem)
+}
+
+/// The Renovate managers covering the same files as a Dependabot ecosystem.
+pub fn managers_for_ecosystem(ecosystem: &Ecosystem) -> Vec<&'static str> {
+    MANAGERS
+        .iter()
+        .filter(|(_, candidate)| candidate == ecosystem)
+        .map(|(manager, _)| *manager)
+        .collect()
+}
+
+/// Renovate accepts a single string wherever it accepts a list of schedules.
+fn string_or_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
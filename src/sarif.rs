@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! SARIF 2.1.0 output, which GitHub code scanning shows in the Security tab
+//! and as annotations on pull requests.
+
+use crate::finding::{Finding, FindingKind, Severity};
+use crate::{DependencyReport, Error};
+use serde_json::json;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/black-desk/up2date";
+
+pub fn sarif_report(report: &DependencyReport) -> Result<String, Error> {
+    let json = serde_json::to_string_pretty(&sarif_log(report))
+        .map_err(|err| Error::render("sarif", err))?;
+    Ok(format!("{}\n", json))
+}
+
+fn sarif_log(report: &DependencyReport) -> serde_json::Value {
+    let rules: Vec<serde_json::Value> = FindingKind::ALL
+        .iter()
+        .map(|kind| {
+            json!({
+                "id": kind.as_str(),
+                "shortDescription": { "text": kind.description() },
+                "defaultConfiguration": { "level": level(kind.severity()) },
+                "helpUri": INFORMATION_URI,
+            })
+        })
+        .collect();
+
+    let mut results: Vec<serde_json::Value> = report.all_findings().iter().map(result).collect();
+    // Suppressed results stay in the log so that code scanning closes the
+    // alerts as dismissed instead of fixed.
+    results.extend(report.suppressed.iter().map(|suppressed| {
+        let mut result = result(&suppressed.finding);
+        let mut suppression = json!({ "kind": "external" });
+        if let Some(justification) = &suppressed.suppression.justification {
+            suppression["justification"] = json!(justification);
+        }
+        result["suppressions"] = json!([suppression]);
+        result
+    }));
+
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "up2date",
+                    "informationUri": INFORMATION_URI,
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn result(finding: &Finding) -> serde_json::Value {
+    let rule_index = FindingKind::ALL
+        .iter()
+        .position(|kind| *kind == finding.kind)
+        .unwrap();
+
+    json!({
+        "ruleId": finding.kind.as_str(),
+        "ruleIndex": rule_index,
+        "level": level(finding.severity),
+        "message": { "text": finding.message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": {
+                    "uri": finding.file,
+                    "uriBaseId": "%SRCROOT%",
+                },
+                "region": { "startLine": finding.line.unwrap_or(1) },
+            },
+        }],
+    })
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
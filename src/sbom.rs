@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! CycloneDX and SPDX documents listing the detected manifests, with
+//! properties recording their ecosystem and whether Dependabot updates them.
+
+use crate::date::civil_from_days;
+use crate::{DependencyReport, Error, ProjectDependency};
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TOOL_NAME: &str = "up2date";
+
+struct Manifest<'a> {
+    path: &'a str,
+    ecosystem: &'a str,
+    directory: &'a str,
+    covered: bool,
+    sha1: Option<String>,
+}
+
+pub fn cyclonedx_report(report: &DependencyReport, project_root: &Path) -> Result<String, Error> {
+    let components: Vec<serde_json::Value> = manifests(report, project_root)
+        .iter()
+        .map(|manifest| {
+            let mut component = json!({
+                "type": "file",
+                "bom-ref": format!("file:{}", manifest.path),
+                "name": manifest.path,
+                "properties": [
+                    { "name": "up2date:ecosystem", "value": manifest.ecosystem },
+                    { "name": "up2date:directory", "value": manifest.directory },
+                    { "name": "up2date:covered", "value": manifest.covered.to_string() },
+                ],
+            });
+            if let Some(sha1) = &manifest.sha1 {
+                component["hashes"] = json!([{ "alg": "SHA-1", "content": sha1 }]);
+            }
+            component
+        })
+        .collect();
+
+    let bom = json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp(),
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": TOOL_NAME,
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+            "component": {
+                "type": "application",
+                "bom-ref": "project",
+                "name": project_name(project_root),
+            },
+        },
+        "components": components,
+    });
+
+    let json = serde_json::to_string_pretty(&bom).map_err(|err| Error::render("cyclonedx", err))?;
+    Ok(format!("{}\n", json))
+}
+
+pub fn spdx_report(report: &DependencyReport, project_root: &Path) -> Result<String, Error> {
+    let created = timestamp();
+    let creator = format!("Tool: {}-{}", TOOL_NAME, env!("CARGO_PKG_VERSION"));
+    let manifests = manifests(report, project_root);
+
+    // SPDX requires a SHA-1 checksum for every file, so manifests that
+    // cannot be read are only named in the comment of the document.
+    let (manifests, unreadable): (Vec<Manifest>, Vec<Manifest>) = manifests
+        .into_iter()
+        .partition(|manifest| manifest.sha1.is_some());
+
+    let mut files = Vec::new();
+    let mut relationships = Vec::new();
+    for (index, manifest) in manifests.iter().enumerate() {
+        let id = format!("SPDXRef-File-{}", index + 1);
+        files.push(json!({
+            "fileName": format!("./{}", manifest.path),
+            "SPDXID": id,
+            "checksums": [{
+                "algorithm": "SHA1",
+                "checksumValue": manifest.sha1,
+            }],
+            "annotations": [{
+                "annotationType": "OTHER",
+                "annotator": creator,
+                "annotationDate": created,
+                "comment": format!(
+                    "ecosystem={}; directory={}; covered={}",
+                    manifest.ecosystem, manifest.directory, manifest.covered
+                ),
+            }],
+        }));
+        relationships.push(json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": id,
+        }));
+    }
+
+    // The namespace only has to be unique per document, so it is derived
+    // from everything the document records.
+    let mut hasher = sha1_smol::Sha1::new();
+    hasher.update(created.as_bytes());
+    for manifest in &manifests {
+        hasher.update(manifest.path.as_bytes());
+        hasher.update(manifest.sha1.as_deref().unwrap_or_default().as_bytes());
+    }
+    let name = project_name(project_root);
+
+    let mut document = json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": name,
+        "documentNamespace": format!(
+            "https://github.com/black-desk/up2date/spdx/{}-{}",
+            percent_encode(&name),
+            hasher.digest()
+        ),
+        "creationInfo": {
+            "created": created,
+            "creators": [creator],
+        },
+        "files": files,
+        "relationships": relationships,
+    });
+    if !unreadable.is_empty() {
+        let paths: Vec<String> = unreadable
+            .iter()
+            .map(|manifest| format!("./{}", manifest.path))
+            .collect();
+        document["comment"] = json!(format!(
+            "Manifests that cannot be read are not listed: {}",
+            paths.join(", ")
+        ));
+    }
+
+    let json = serde_json::to_string_pretty(&document).map_err(|err| Error::render("spdx", err))?;
+    Ok(format!("{}\n", json))
+}
+
+fn manifests<'a>(report: &'a DependencyReport, project_root: &Path) -> Vec<Manifest<'a>> {
+    let mut dependencies: Vec<&ProjectDependency> = report.project_dependencies.iter().collect();
+    dependencies.sort_by(|a, b| (&a.ecosystem, &a.directory).cmp(&(&b.ecosystem, &b.directory)));
+
+    dependencies
+        .into_iter()
+        .flat_map(|dep| {
+            let covered = !report.missing_from_dependabot.contains(&dep.ecosystem);
+            dep.manifests.iter().map(move |path| Manifest {
+                path,
+                ecosystem: dep.ecosystem.name(),
+                directory: &dep.directory,
+                covered,
+                sha1: fs::read(project_root.join(path))
+                    .ok()
+                    .map(|content| sha1_smol::Sha1::from(content).digest().to_string()),
+            })
+        })
+        .collect()
+}
+
+fn project_name(project_root: &Path) -> String {
+    project_root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "project".to_string())
+}
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986,
+/// so that any directory name can be part of a URI.
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// The current time in RFC 3339, or `SOURCE_DATE_EPOCH` when it is set so
+/// that documents can be reproduced.
+fn timestamp() -> String {
+    let seconds = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default()
+        });
+
+    let days = seconds / 86400;
+    let time = seconds % 86400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
@@ -0,0 +1,250 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `up2date: ignore` comments, which suppress findings from the file they
+//! are about:
+//!
+//! ```text
+//! # up2date: ignore npm -- docs site, updated manually
+//! ```
+//!
+//! In a manifest the comment suppresses the missing coverage of the
+//! ecosystem in the directory of the manifest. JSON manifests, such as
+//! `package.json`, have no comments and are not scanned. In the Dependabot
+//! configuration it suppresses the findings about the `updates` entry it is
+//! written in or directly above, such as a stale entry.
+
+use crate::dependabot;
+use crate::finding::{Finding, FindingKind, Suppression};
+use crate::validate::normalize_directory;
+use crate::{DependencyReport, Ecosystem, ProjectDependency};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The comment, after the marker of the format it is written in.
+const COMMENT: &str = r"\s*up2date:\s*ignore\s+(\S+)(?:\s+--\s*(.*?))?\s*$";
+
+struct InlineSuppression {
+    file: String,
+    line: usize,
+    /// The name as written, which for an unknown `package-ecosystem` is not
+    /// the name of the ecosystem it is an alias of.
+    name: String,
+    ecosystem: Ecosystem,
+    target: Target,
+    justification: Option<String>,
+}
+
+enum Target {
+    /// Missing coverage in a directory, for comments in manifests.
+    Directory(Option<String>),
+    /// An `updates` entry by index, for comments in the Dependabot
+    /// configuration. `None` when the comment is next to no entry.
+    Entry(Option<usize>),
+}
+
+impl InlineSuppression {
+    fn matches(&self, finding: &Finding) -> bool {
+        let Some(ecosystem) = &finding.ecosystem else {
+            return false;
+        };
+        if *ecosystem != self.ecosystem && ecosystem.name() != self.name {
+            return false;
+        }
+        match &self.target {
+            Target::Directory(directory) => {
+                finding.kind == FindingKind::MissingEcosystem
+                    && directory.as_deref().is_some_and(|directory| {
+                        finding.directory.as_deref().map(normalize_directory)
+                            == Some(normalize_directory(directory))
+                    })
+            }
+            Target::Entry(entry) => {
+                finding.file == self.file
+                    && entry.is_some_and(|entry| finding.entries.contains(&entry))
+            }
+        }
+    }
+}
+
+/// Suppresses the findings of `report` that an `up2date: ignore` comment in
+/// a manifest or the Dependabot configuration covers, and reports the
+/// comments that cover none.
+pub(crate) fn apply_inline_suppressions(project_root: &Path, report: &mut DependencyReport) {
+    let suppressions = find_inline_suppressions(project_root, &report.project_dependencies);
+    let mut used = vec![false; suppressions.len()];
+
+    report.suppress(|finding| {
+        let index = suppressions
+            .iter()
+            .position(|suppression| suppression.matches(finding))?;
+        used[index] = true;
+        let suppression = &suppressions[index];
+        Some(Suppression {
+            suppressed_by: format!("{}:{}", suppression.file, suppression.line),
+            justification: suppression.justification.clone(),
+        })
+    });
+
+    for (suppression, used) in suppressions.into_iter().zip(used) {
+        if used {
+            continue;
+        }
+        let mut finding = Finding::new(
+            FindingKind::UnusedSuppression,
+            suppression.file,
+            format!(
+                "`up2date: ignore {}` does not suppress any finding; remove it",
+                suppression.ecosystem
+            ),
+        )
+        .with_line(Some(suppression.line));
+        finding.ecosystem = Some(suppression.ecosystem);
+        if let Target::Directory(directory) = suppression.target {
+            finding.directory = directory;
+        }
+        report.findings.push(finding);
+    }
+}
+
+fn find_inline_suppressions(
+    project_root: &Path,
+    project_dependencies: &[ProjectDependency],
+) -> Vec<InlineSuppression> {
+    let mut comments: HashMap<&str, Regex> = HashMap::new();
+    let mut comment = |markers: &'static str| {
+        comments
+            .entry(markers)
+            .or_insert_with(|| Regex::new(&format!("(?:{}){}", markers, COMMENT)).unwrap())
+            .clone()
+    };
+    let mut suppressions = Vec::new();
+    let mut scanned: Vec<&str> = Vec::new();
+
+    for manifest in project_dependencies
+        .iter()
+        .flat_map(|dep| dep.manifests.iter())
+    {
+        if scanned.contains(&manifest.as_str()) {
+            continue;
+        }
+        scanned.push(manifest);
+
+        let file_name = manifest.rsplit('/').next().unwrap_or(manifest);
+        let Some(markers) = comment_markers(file_name) else {
+            continue;
+        };
+        let comment = comment(markers);
+        // Files that cannot be read are reported elsewhere.
+        let Ok(content) = fs::read_to_string(project_root.join(manifest)) else {
+            continue;
+        };
+        for (line, name, justification) in scan(&content, &comment) {
+            let ecosystem = resolve(&name);
+            // The comment is about the directory the manifest was detected
+            // in for that ecosystem, falling back to any directory it was
+            // detected in.
+            let detected_in = |dep: &&ProjectDependency| dep.manifests.contains(manifest);
+            let directory = project_dependencies
+                .iter()
+                .filter(detected_in)
+                .find(|dep| dep.ecosystem == ecosystem)
+                .or_else(|| project_dependencies.iter().find(detected_in))
+                .map(|dep| dep.directory.clone());
+
+            suppressions.push(InlineSuppression {
+                file: manifest.clone(),
+                line,
+                name,
+                ecosystem,
+                target: Target::Directory(directory),
+                justification,
+            });
+        }
+    }
+
+    for path in dependabot::CONFIG_PATHS {
+        let Ok(content) = fs::read_to_string(project_root.join(path)) else {
+            continue;
+        };
+        let lines: Vec<&str> = content.lines().collect();
+        let entry_lines = dependabot::update_entry_lines(&content);
+        for (line, name, justification) in scan(&content, &comment("#")) {
+            suppressions.push(InlineSuppression {
+                file: path.to_string(),
+                line,
+                ecosystem: resolve(&name),
+                name,
+                target: Target::Entry(entry_next_to(&lines, &entry_lines, line)),
+                justification,
+            });
+        }
+    }
+
+    suppressions
+}
+
+/// The comment markers, as a regex alternation, of a manifest with this
+/// file name. `None` for formats without comments, such as JSON.
+fn comment_markers(file_name: &str) -> Option<&'static str> {
+    if file_name.ends_with(".json") {
+        return None;
+    }
+    match file_name {
+        "go.mod" => Some("//"),
+        _ if Ecosystem::from_manifest(file_name).is_some() => Some("#"),
+        // Manifests of `[[detect]]` rules and workflows, whose format is not
+        // known.
+        _ => Some("#|//"),
+    }
+}
+
+/// The `up2date: ignore` comments of `content`, with their line numbers and
+/// the ecosystem names as written.
+fn scan(content: &str, comment: &Regex) -> Vec<(usize, String, Option<String>)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let captures = comment.captures(line)?;
+            let justification = captures
+                .get(2)
+                .map(|reason| reason.as_str().to_string())
+                .filter(|reason| !reason.is_empty());
+            Some((index + 1, captures[1].to_string(), justification))
+        })
+        .collect()
+}
+
+fn resolve(name: &str) -> Ecosystem {
+    Ecosystem::from_alias(name).unwrap_or_else(|| Ecosystem::from(name))
+}
+
+/// The index of the `updates` entry a comment on the 1-based `line` is
+/// about: the entry whose lines it is written on, or the one it directly
+/// precedes with only comments and blank lines in between.
+fn entry_next_to(lines: &[&str], entry_lines: &[usize], line: usize) -> Option<usize> {
+    let is_blank_or_comment = |line: usize| {
+        let trimmed = lines[line - 1].trim_start();
+        trimmed.is_empty() || trimmed.starts_with('#')
+    };
+
+    if is_blank_or_comment(line) {
+        if let Some(entry) = entry_lines.iter().position(|start| *start > line) {
+            if (line + 1..entry_lines[entry]).all(is_blank_or_comment) {
+                return Some(entry);
+            }
+        }
+    }
+
+    let entry = entry_lines.iter().rposition(|start| *start <= line)?;
+    // The last entry ends at the next top-level key.
+    let ends_before = (entry_lines[entry]..=line).skip(1).any(|line| {
+        let text = lines[line - 1];
+        !is_blank_or_comment(line) && !text.starts_with([' ', '\t', '-'])
+    });
+    (!ends_before).then_some(entry)
+}
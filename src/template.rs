@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Reports rendered from Jinja templates, which receive the serialized
+//! `DependencyReport`, or `ReportDiff` for diffs, as their context.
+
+use crate::DependencyReport;
+use minijinja::{context, Environment, Value};
+use serde::Serialize;
+
+/// The built-in Markdown report.
+pub const DEFAULT_TEMPLATE: &str = include_str!("report.md.j2");
+
+/// The built-in Markdown report of a diff.
+pub const DIFF_TEMPLATE: &str = include_str!("diff.md.j2");
+
+/// Renders `report` with `source`, adding `missing` to the context: the
+/// ecosystems of `missing_from_dependabot` with missing coverage that is not
+/// suppressed in at least one directory.
+pub fn render_report(
+    name: &str,
+    source: &str,
+    report: &DependencyReport,
+) -> Result<String, String> {
+    let missing: Vec<_> = report
+        .missing_from_dependabot
+        .iter()
+        .filter(|ecosystem| {
+            report.project_dependencies.iter().any(|dep| {
+                &dep.ecosystem == *ecosystem
+                    && !report.is_suppressed(&dep.ecosystem, &dep.directory)
+            })
+        })
+        .collect();
+
+    render(
+        name,
+        source,
+        &context! { missing => missing, ..Value::from_serialize(report) },
+    )
+}
+
+/// Renders `source`, whose `name` decides auto-escaping the way Jinja does:
+/// templates ending in `.html` or `.xml` escape HTML.
+pub fn render(name: &str, source: &str, report: &impl Serialize) -> Result<String, String> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.add_template(name, source)
+        .map_err(|e| format!("{:#}", e))?;
+
+    env.get_template(name)
+        .and_then(|template| template.render(report))
+        .map_err(|e| format!("{:#}", e))
+}
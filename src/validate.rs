@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::dependabot::{DependabotFile, UpdateConfig};
+use crate::ecosystem::{self, Ecosystem};
+use crate::finding::{Finding, FindingKind};
+use globset::GlobBuilder;
+use std::collections::HashSet;
+
+/// Checks every `package-ecosystem` against the names Dependabot accepts.
+///
+/// An unknown name matches no dependency and Dependabot rejects the whole
+/// configuration, so the closest valid name is suggested.
+pub fn check_ecosystem_names(dependabot: &DependabotFile) -> Vec<Finding> {
+    dependabot
+        .config
+        .updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| !update.package_ecosystem.is_known())
+        .map(|(index, update)| {
+            let mut message = format!(
+                "unknown package-ecosystem `{}` in updates[{}]",
+                update.package_ecosystem, index
+            );
+            if let Some(suggestion) = update.package_ecosystem.suggest() {
+                message.push_str(&format!("; did you mean `{}`?", suggestion));
+            }
+            message.push_str(&format!(" ({})", ecosystem::ALIASES_NOTE));
+
+            Finding::new(FindingKind::UnknownEcosystem, dependabot.path, message)
+                .with_line(dependabot.entry_line(index))
+                .with_entries(vec![index])
+                .with_updates([update])
+        })
+        .collect()
+}
+
+/// Finds `updates` entries for ecosystems that the project does not use.
+///
+/// Only ecosystems this tool can detect are checked, so that entries for
+/// other ecosystems are not reported just because their manifests are
+/// unknown here.
+pub fn check_stale_entries(
+    dependabot: &DependabotFile,
+    project_ecosystems: &HashSet<Ecosystem>,
+) -> Vec<Finding> {
+    dependabot
+        .config
+        .updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| {
+            update.package_ecosystem.is_detectable()
+                && !project_ecosystems.contains(&update.package_ecosystem)
+        })
+        .map(|(index, update)| {
+            Finding::new(
+                FindingKind::StaleEntry,
+                dependabot.path,
+                format!(
+                    "updates[{}] configures `{}` but the project has no `{}` dependencies",
+                    index, update.package_ecosystem, update.package_ecosystem
+                ),
+            )
+            .with_line(dependabot.entry_line(index))
+            .with_entries(vec![index])
+            .with_updates([update])
+            .with_dependency(
+                &update.package_ecosystem,
+                &update.all_directories().join(", "),
+            )
+        })
+        .collect()
+}
+
+/// Checks the `updates` entries against each other.
+///
+/// Dependabot rejects two entries for the same ecosystem, directory and
+/// target branch, and opens duplicate pull requests when the `directories`
+/// globs of such entries overlap.
+pub fn check_update_entries(dependabot: &DependabotFile) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let updates = &dependabot.config.updates;
+
+    for (j, second) in updates.iter().enumerate() {
+        for (i, first) in updates.iter().enumerate().take(j) {
+            if first.package_ecosystem != second.package_ecosystem
+                || first.target_branch != second.target_branch
+            {
+                continue;
+            }
+
+            let Some((kind, message)) = compare_directories(first, second, i, j) else {
+                continue;
+            };
+
+            findings.push(
+                Finding::new(kind, dependabot.path, message)
+                    .with_line(dependabot.entry_line(j))
+                    .with_entries(vec![i, j])
+                    .with_updates([first, second]),
+            );
+        }
+    }
+
+    findings
+}
+
+fn compare_directories(
+    first: &UpdateConfig,
+    second: &UpdateConfig,
+    i: usize,
+    j: usize,
+) -> Option<(FindingKind, String)> {
+    let first_directories: Vec<String> = first
+        .all_directories()
+        .into_iter()
+        .map(normalize_directory)
+        .collect();
+    let second_directories: Vec<String> = second
+        .all_directories()
+        .into_iter()
+        .map(normalize_directory)
+        .collect();
+
+    let target = match &first.target_branch {
+        Some(branch) => format!(" targeting `{}`", branch),
+        None => String::new(),
+    };
+
+    for a in &first_directories {
+        if let Some(b) = second_directories.iter().find(|b| *b == a) {
+            return Some((
+                FindingKind::DuplicateUpdate,
+                format!(
+                    "updates[{}] and updates[{}] both configure `{}` in `{}`{}",
+                    i, j, first.package_ecosystem, b, target
+                ),
+            ));
+        }
+    }
+
+    for a in &first_directories {
+        if let Some(b) = second_directories.iter().find(|b| overlaps(a, b)) {
+            return Some((
+                FindingKind::OverlappingDirectories,
+                format!(
+                    "directories `{}` of updates[{}] and `{}` of updates[{}] overlap for `{}`{}, \
+                     which causes duplicate pull requests",
+                    a, i, b, j, first.package_ecosystem, target
+                ),
+            ));
+        }
+    }
+
+    None
+}
+
+/// `directory` with a leading `/` and no trailing one, `/` for the root.
+pub(crate) fn normalize_directory(directory: &str) -> String {
+    let directory = directory.trim().trim_start_matches("./").trim_matches('/');
+    if directory.is_empty() || directory == "." {
+        "/".to_string()
+    } else {
+        format!("/{}", directory)
+    }
+}
+
+/// Whether either glob matches the other pattern taken literally, which
+/// catches `/packages/*` against `/packages/app` or `/packages/**`.
+fn overlaps(a: &str, b: &str) -> bool {
+    matches_glob(a, b) || matches_glob(b, a)
+}
+
+fn matches_glob(pattern: &str, path: &str) -> bool {
+    if !pattern.contains(['*', '?', '[', '{']) {
+        return false;
+    }
+
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|glob| glob.compile_matcher().is_match(path))
+        .unwrap_or(false)
+}
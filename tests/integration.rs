@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use assert_cmd::Command;
+use assert_cmd::cargo::cargo_bin_cmd;
 use predicates::prelude::*;
 use std::fs;
 use tempfile::TempDir;
@@ -139,7 +139,7 @@ edition = "2021"
 
 #[test]
 fn test_help_output() {
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.arg("--help");
     cmd.assert()
         .success()
@@ -150,8 +150,9 @@ fn test_help_output() {
 fn test_complete_project_markdown_output() {
     let temp_dir = create_test_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
+    cmd.env_remove("GITHUB_ACTIONS");
     cmd.assert()
         .success()
         .stdout(predicate::str::is_empty().not());
@@ -161,7 +162,7 @@ fn test_complete_project_markdown_output() {
 fn test_complete_project_json_output() {
     let temp_dir = create_test_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -204,7 +205,7 @@ fn test_complete_project_json_output() {
 fn test_incomplete_project() {
     let temp_dir = create_incomplete_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -243,7 +244,7 @@ fn test_incomplete_project() {
 fn test_yaml_output_format() {
     let temp_dir = create_test_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--yaml");
 
@@ -287,7 +288,7 @@ fn test_yaml_output_format() {
 fn test_toml_output_format() {
     let temp_dir = create_test_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--toml");
 
@@ -342,7 +343,7 @@ RUN cargo build --release
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -386,7 +387,7 @@ RUN apt-get update
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -421,7 +422,7 @@ RUN apt-get update
 fn test_empty_project() {
     let temp_dir = TempDir::new().unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -474,7 +475,7 @@ go 1.19
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -513,7 +514,7 @@ go 1.19
 fn test_exit_code_success_when_all_covered() {
     let temp_dir = create_test_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -525,7 +526,7 @@ fn test_exit_code_success_when_all_covered() {
 fn test_exit_code_failure_when_missing_coverage() {
     let temp_dir = create_incomplete_project();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -537,7 +538,7 @@ fn test_exit_code_failure_when_missing_coverage() {
 fn test_exit_code_success_when_empty() {
     let temp_dir = TempDir::new().unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -565,7 +566,7 @@ runs:
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -613,7 +614,7 @@ runs:
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -660,7 +661,7 @@ runs:
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -730,7 +731,7 @@ runs:
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -796,7 +797,7 @@ updates:
     )
     .unwrap();
 
-    let mut cmd = Command::cargo_bin("up2date").unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
     cmd.current_dir(temp_dir.path());
     cmd.arg("--json");
 
@@ -823,3 +824,1890 @@ updates:
 
     assert_json_eq_unordered(&actual, &expected);
 }
+
+#[test]
+fn test_convert_renovate_to_dependabot() {
+    let temp_dir = TempDir::new().unwrap();
+
+    fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+    fs::create_dir_all(temp_dir.path().join("frontend")).unwrap();
+    fs::write(temp_dir.path().join("frontend/package.json"), "{}").unwrap();
+    fs::write(
+        temp_dir.path().join("renovate.json"),
+        r#"{
+  "schedule": ["before 4am on monday"],
+  "labels": ["dependencies"],
+  "automerge": true,
+  "packageRules": [
+    {
+      "matchManagers": ["npm"],
+      "matchPackageNames": ["@angular/*"],
+      "groupName": "Angular packages"
+    },
+    {
+      "matchManagers": ["cargo"],
+      "matchUpdateTypes": ["major"],
+      "enabled": false
+    }
+  ]
+}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["convert", "--to", "dependabot"]);
+
+    let assert = cmd.assert().success().stderr(predicate::str::contains(
+        "`automerge` has no Dependabot equivalent",
+    ));
+    let yaml_str = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    let actual: serde_yaml::Value = serde_yaml::from_str(&yaml_str).unwrap();
+    let actual_json: serde_json::Value = serde_json::to_value(actual).unwrap();
+
+    let schedule = serde_json::json!({
+        "interval": "weekly",
+        "day": "monday",
+        "time": "03:00"
+    });
+    let expected = serde_json::json!({
+        "version": 2,
+        "updates": [
+            {
+                "package-ecosystem": "cargo",
+                "directory": "/",
+                "schedule": schedule,
+                "labels": ["dependencies"],
+                "ignore": [
+                    {
+                        "dependency-name": "*",
+                        "update-types": ["version-update:semver-major"]
+                    }
+                ]
+            },
+            {
+                "package-ecosystem": "npm",
+                "directory": "/frontend",
+                "schedule": schedule,
+                "labels": ["dependencies"],
+                "groups": {
+                    "angular-packages": {
+                        "patterns": ["@angular/*"]
+                    }
+                }
+            }
+        ]
+    });
+
+    assert_json_eq_unordered(&actual_json, &expected);
+}
+
+#[test]
+fn test_convert_renovate_config_locations() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+    fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{
+  "renovate": {
+    "packageRules": [{ "matchManagers": ["cargo", "regex"], "enabled": false }]
+  }
+}
+"#,
+    )
+    .unwrap();
+
+    let convert = || {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path());
+        cmd.args(["convert", "--to", "dependabot"]);
+        cmd.assert()
+    };
+
+    convert()
+        .success()
+        .stdout(predicate::str::contains("package-ecosystem: npm"))
+        .stdout(predicate::str::contains("cargo").not())
+        .stderr(predicate::str::contains(
+            "package.json: packageRules[0]: manager `regex` has no Dependabot equivalent \
+             and was dropped\n",
+        ));
+
+    fs::write(temp_dir.path().join(".renovaterc"), "{}").unwrap();
+    convert()
+        .success()
+        .stdout(predicate::str::contains("package-ecosystem: cargo"))
+        .stderr(predicate::str::contains(
+            ".renovaterc: `package.json` was not converted, Renovate only reads the first \
+             configuration it finds\n",
+        ));
+
+    fs::create_dir_all(temp_dir.path().join(".gitlab")).unwrap();
+    fs::write(temp_dir.path().join(".gitlab/renovate.json5"), "{}").unwrap();
+    convert().code(3).stderr(predicate::str::contains(
+        ".gitlab/renovate.json5: JSON5 configuration is not supported",
+    ));
+}
+
+#[test]
+fn test_convert_dependabot_to_renovate() {
+    let temp_dir = create_test_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["convert", "--to", "renovate"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json_str = String::from_utf8(output).unwrap();
+    let actual: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    let expected = serde_json::json!({
+        "$schema": "https://docs.renovatebot.com/renovate-schema.json",
+        "enabledManagers": ["cargo", "git-submodules", "github-actions"],
+        "git-submodules": { "enabled": true }
+    });
+
+    assert_json_eq_unordered(&actual, &expected);
+}
+
+#[test]
+fn test_convert_rejects_options_of_the_check() {
+    let temp_dir = create_test_project();
+
+    for (args, message) in [
+        (
+            &["--fail-on", "stale", "convert", "--to", "renovate"][..],
+            "error: `--fail-on` cannot be used with `convert`\n",
+        ),
+        (
+            &["--exclude", "vendor", "convert", "--to", "renovate"][..],
+            "error: `--exclude` cannot be used with `convert`\n",
+        ),
+        (
+            &["--json", "convert", "--to", "renovate"][..],
+            "error: `--format json` cannot be used with `convert`, which prints the \
+             converted configuration\n",
+        ),
+    ] {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path()).args(args);
+        cmd.assert().code(2).stderr(message);
+    }
+}
+
+#[test]
+fn test_convert_without_source_configuration() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["convert", "--to", "dependabot"]);
+
+    cmd.assert()
+        .code(3)
+        .stderr(predicate::str::contains("no Renovate configuration found"));
+}
+
+fn create_legacy_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+
+    fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".dependabot")).unwrap();
+    fs::write(
+        temp_dir.path().join(".dependabot/config.yml"),
+        r#"version: 1
+update_configs:
+  - package_manager: "rust:cargo"
+    directory: "/"
+    update_schedule: "live"
+    default_labels:
+      - "dependencies"
+    ignored_updates:
+      - match:
+          dependency_name: "serde"
+          version_requirement: ">= 2.0"
+    automerged_updates:
+      - match:
+          dependency_type: "all"
+"#,
+    )
+    .unwrap();
+
+    temp_dir
+}
+
+#[test]
+fn test_legacy_dependabot_config_is_blocking() {
+    let temp_dir = create_legacy_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json");
+
+    let output = cmd.assert().code(4).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let findings = actual["findings"].as_array().unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0]["kind"], "legacy-config");
+    assert_eq!(findings[0]["severity"], "error");
+    assert_eq!(findings[0]["file"], ".dependabot/config.yml");
+}
+
+#[test]
+fn test_convert_legacy_dependabot_config() {
+    let temp_dir = create_legacy_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["convert", "--to", "dependabot"]);
+
+    let assert = cmd
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "live updates are no longer available",
+        ))
+        .stderr(predicate::str::contains(
+            "`automerged_updates` has no version 2 equivalent",
+        ));
+    let yaml_str = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    let actual: serde_yaml::Value = serde_yaml::from_str(&yaml_str).unwrap();
+    let actual_json: serde_json::Value = serde_json::to_value(actual).unwrap();
+
+    let expected = serde_json::json!({
+        "version": 2,
+        "updates": [
+            {
+                "package-ecosystem": "cargo",
+                "directory": "/",
+                "schedule": { "interval": "daily" },
+                "labels": ["dependencies"],
+                "ignore": [
+                    {
+                        "dependency-name": "serde",
+                        "versions": [">= 2.0"]
+                    }
+                ]
+            }
+        ]
+    });
+
+    assert_json_eq_unordered(&actual_json, &expected);
+}
+
+#[test]
+fn test_duplicate_dependabot_config_files() {
+    let temp_dir = create_test_project();
+
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - directory: /
+    package-ecosystem: cargo
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json");
+
+    let output = cmd.assert().code(4).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    // The `.yml` file is used, the `.yaml` file is reported
+    assert_eq!(
+        actual["dependabot_ecosystems"],
+        serde_json::json!(["cargo"])
+    );
+    let findings = actual["findings"].as_array().unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0]["kind"], "duplicate-config-file");
+    assert_eq!(findings[0]["file"], ".github/dependabot.yaml");
+}
+
+#[test]
+fn test_invalid_dependabot_config_does_not_fall_back() {
+    let temp_dir = create_test_project();
+
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        "version: 2\nupdates: {\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json");
+
+    let output = cmd.assert().code(4).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(actual["dependabot_ecosystems"], serde_json::json!([]));
+    let kinds: Vec<&str> = actual["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|finding| finding["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds, ["duplicate-config-file", "invalid-config"]);
+    assert_eq!(actual["findings"][1]["file"], ".github/dependabot.yml");
+}
+
+#[test]
+fn test_duplicate_and_overlapping_update_entries() {
+    let temp_dir = TempDir::new().unwrap();
+
+    fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+    fs::create_dir_all(temp_dir.path().join("packages/app")).unwrap();
+    fs::write(temp_dir.path().join("packages/app/package.json"), "{}").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: npm
+    directories:
+      - /packages/*
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: weekly
+  - package-ecosystem: npm
+    directory: /packages/app
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directory: /
+    target-branch: release
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json");
+
+    let output = cmd.assert().code(4).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let expected = serde_json::json!([
+        {
+            "kind": "duplicate-update",
+            "severity": "error",
+            "file": ".github/dependabot.yml",
+            "line": 12,
+            "ecosystem": "cargo",
+            "directories": ["/"],
+            "entries": [0, 2],
+            "message": "updates[0] and updates[2] both configure `cargo` in `/`"
+        },
+        {
+            "kind": "overlapping-directories",
+            "severity": "warning",
+            "file": ".github/dependabot.yml",
+            "line": 16,
+            "ecosystem": "npm",
+            "directories": ["/packages/*", "/packages/app"],
+            "entries": [1, 3],
+            "message": "directories `/packages/*` of updates[1] and `/packages/app` of updates[3] \
+                        overlap for `npm`, which causes duplicate pull requests"
+        }
+    ]);
+
+    assert_eq!(actual["findings"], expected);
+}
+
+#[test]
+fn test_unknown_ecosystem_suggestions() {
+    let temp_dir = TempDir::new().unwrap();
+
+    fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: rust
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: github-action
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: yarn
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json");
+
+    let output = cmd.assert().code(4).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    // Unknown names do not count as configured
+    assert_eq!(actual["dependabot_ecosystems"], serde_json::json!([]));
+    assert_eq!(
+        actual["missing_from_dependabot"],
+        serde_json::json!(["cargo"])
+    );
+
+    let findings = actual["findings"].as_array().unwrap();
+    let suggestions: Vec<(&str, u64)> = findings
+        .iter()
+        .map(|finding| {
+            assert_eq!(finding["kind"], "unknown-ecosystem");
+            let message = finding["message"].as_str().unwrap();
+            assert!(message.contains("Yarn and pnpm projects use `npm`"));
+            let suggestion = message.split("did you mean `").nth(1).unwrap();
+            (
+                suggestion.split('`').next().unwrap(),
+                finding["line"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        suggestions,
+        [("cargo", 3), ("github-actions", 7), ("npm", 11)]
+    );
+}
+
+#[test]
+fn test_sarif_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: gomod
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--sarif");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(actual["version"], "2.1.0");
+    let run = &actual["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "up2date");
+
+    let results: Vec<(&str, &str, &str, u64)> = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| {
+            let location = &result["locations"][0]["physicalLocation"];
+            (
+                result["ruleId"].as_str().unwrap(),
+                result["level"].as_str().unwrap(),
+                location["artifactLocation"]["uri"].as_str().unwrap(),
+                location["region"]["startLine"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        results,
+        [
+            ("missing-ecosystem", "error", "package.json", 1),
+            ("stale-entry", "warning", ".github/dependabot.yml", 7),
+        ]
+    );
+}
+
+#[test]
+fn test_junit_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--junit");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let actual = String::from_utf8(output).unwrap();
+
+    assert_eq!(
+        actual,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="up2date" tests="2" failures="1" errors="0">
+  <testsuite name="dependabot-coverage" tests="2" failures="1" errors="0" skipped="0">
+    <testcase classname="up2date.cargo" name="cargo in ." file="Cargo.toml"/>
+    <testcase classname="up2date.npm" name="npm in ." file="package.json">
+      <failure type="missing-ecosystem" message="package.json is not updated by Dependabot: no `npm` entry in the Dependabot configuration">package.json is not updated by Dependabot: no `npm` entry in the Dependabot configuration</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
+
+#[test]
+fn test_github_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: gomod
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let summary = temp_dir.path().join("summary.md");
+    fs::write(&summary, "# Earlier step\n\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.env("GITHUB_STEP_SUMMARY", &summary);
+    cmd.args(["--format", "github"]);
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "::error file=package.json,title=missing-ecosystem::\
+         `npm` dependencies in `.` are not updated by Dependabot\n\
+         ::warning file=.github/dependabot.yml,line=7,title=stale-entry::\
+         updates[1] configures `gomod` but the project has no `gomod` dependencies\n"
+    );
+
+    let summary = fs::read_to_string(&summary).unwrap();
+    assert!(summary.starts_with("# Earlier step\n\n# Dependabot Coverage Report\n"));
+    assert!(summary.contains("## Missing from Dependabot\n\n- npm\n"));
+}
+
+#[test]
+fn test_github_output_format_is_default_in_actions() {
+    let temp_dir = create_incomplete_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.env("GITHUB_ACTIONS", "true");
+    cmd.env_remove("GITHUB_STEP_SUMMARY");
+
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::starts_with("::error file=Cargo.toml,"));
+}
+
+#[test]
+fn test_gitlab_codequality_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    let config = r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: gomod
+    directory: /
+    schedule:
+      interval: daily
+"#;
+    fs::write(temp_dir.path().join(".github/dependabot.yml"), config).unwrap();
+
+    let run = || {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path());
+        cmd.args(["--format", "gitlab-codequality"]);
+        let output = cmd.assert().code(1).get_output().stdout.clone();
+        let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        actual
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| {
+                (
+                    issue["check_name"].as_str().unwrap().to_string(),
+                    issue["severity"].as_str().unwrap().to_string(),
+                    issue["location"]["path"].as_str().unwrap().to_string(),
+                    issue["location"]["lines"]["begin"].as_u64().unwrap(),
+                    issue["fingerprint"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let before = run();
+    let summary: Vec<_> = before
+        .iter()
+        .map(|(check, severity, path, line, _)| {
+            (check.as_str(), severity.as_str(), path.as_str(), *line)
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("missing-ecosystem", "major", "package.json", 1),
+            ("stale-entry", "minor", ".github/dependabot.yml", 7),
+        ]
+    );
+    assert_ne!(before[0].4, before[1].4);
+
+    // Fingerprints must survive edits that only move the finding.
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        format!("# Dependabot configuration\n{}", config),
+    )
+    .unwrap();
+    let after = run();
+    assert_eq!(after[1].3, 8);
+    let fingerprints = |issues: &[(String, String, String, u64, String)]| {
+        issues
+            .iter()
+            .map(|issue| issue.4.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(fingerprints(&before), fingerprints(&after));
+
+    // Nor edits that change the index of the entry in `updates`.
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: gomod
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+    let reordered = run();
+    assert_eq!(reordered[1].3, 3);
+    assert_eq!(fingerprints(&before), fingerprints(&reordered));
+
+    // Nor another manifest of the same ecosystem in the same directory,
+    // although the finding now points at it.
+    fs::write(temp_dir.path().join("requirements.txt"), "").unwrap();
+    let pip = |issues: &[(String, String, String, u64, String)]| {
+        issues
+            .iter()
+            .find(|issue| issue.2 == "requirements.txt" || issue.2 == "pyproject.toml")
+            .cloned()
+            .unwrap()
+    };
+    let requirements = pip(&run());
+    fs::write(temp_dir.path().join("pyproject.toml"), "").unwrap();
+    let pyproject = pip(&run());
+    assert_eq!(requirements.2, "requirements.txt");
+    assert_eq!(pyproject.2, "pyproject.toml");
+    assert_eq!(requirements.4, pyproject.4);
+}
+
+#[test]
+fn test_html_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: gomod
+    directory: /tools
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--format", "html"]);
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let html = String::from_utf8(output).unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<tr><th>Directory</th><th>cargo</th><th>gomod</th><th>npm</th></tr>"));
+    assert!(html.contains(
+        "<tr data-missing><td><code>.</code></td>\
+         <td class=\"covered\">covered</td><td></td><td class=\"missing\">missing</td></tr>"
+    ));
+    assert!(html.contains(
+        "<tr data-stale><td><code>tools</code></td>\
+         <td></td><td class=\"stale\">stale</td><td></td></tr>"
+    ));
+    assert!(html
+        .contains("<pre><code>version: 2\nupdates:\n- package-ecosystem: npm\n  directory: /\n"));
+    assert!(
+        html.contains("updates[1] configures `gomod` but the project has no `gomod` dependencies")
+    );
+
+    // Directories are not split on the separator of the message.
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: gomod
+    directories: ["/tools, old", "/legacy"]
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--format", "html"]);
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let html = String::from_utf8(output).unwrap();
+    assert!(html.contains("<tr data-stale><td><code>tools, old</code></td>"));
+    assert!(html.contains("<tr data-stale><td><code>legacy</code></td>"));
+    assert!(!html.contains("<code>old</code>"));
+}
+
+#[test]
+fn test_badge_output() {
+    let temp_dir = create_test_project();
+    let badge = temp_dir.path().join("badge.svg");
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json").arg("--badge").arg(&badge);
+    cmd.assert().success();
+
+    let svg = fs::read_to_string(&badge).unwrap();
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains("aria-label=\"dependabot: 3/3\""));
+    assert!(svg.contains("fill=\"#4c1\""));
+
+    let temp_dir = create_incomplete_project();
+    let badge = temp_dir.path().join("badge.svg");
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.arg("--json").arg("--badge").arg(&badge);
+    cmd.assert().code(1);
+
+    let svg = fs::read_to_string(&badge).unwrap();
+    assert!(svg.contains("aria-label=\"dependabot: 0/2\""));
+    assert!(svg.contains("fill=\"#e05d44\""));
+}
+
+#[test]
+fn test_prometheus_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--format", "prometheus"]);
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let metrics = String::from_utf8(output).unwrap();
+    let samples: Vec<&str> = metrics
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+
+    assert!(metrics.contains("# TYPE up2date_ecosystems_total gauge\n"));
+    assert_eq!(
+        samples[..5],
+        [
+            "up2date_ecosystems_total 2",
+            "up2date_ecosystems_configured 1",
+            "up2date_ecosystems_missing 1",
+            "up2date_dependency_covered{ecosystem=\"cargo\",directory=\".\"} 1",
+            "up2date_dependency_covered{ecosystem=\"npm\",directory=\".\"} 0",
+        ]
+    );
+    assert!(samples.contains(&"up2date_findings{kind=\"missing-ecosystem\",severity=\"error\"} 1"));
+    assert!(samples.contains(&"up2date_findings{kind=\"stale-entry\",severity=\"warning\"} 0"));
+    assert!(!metrics.contains("# EOF"));
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--format", "openmetrics"]);
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::ends_with("# EOF\n"));
+}
+
+#[test]
+fn test_cyclonedx_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.env("SOURCE_DATE_EPOCH", "1700000000");
+    cmd.args(["--format", "cyclonedx"]);
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(actual["bomFormat"], "CycloneDX");
+    assert_eq!(actual["metadata"]["timestamp"], "2023-11-14T22:13:20Z");
+
+    let components: Vec<(&str, Vec<(&str, &str)>)> = actual["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|component| {
+            let properties = component["properties"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|property| {
+                    (
+                        property["name"].as_str().unwrap(),
+                        property["value"].as_str().unwrap(),
+                    )
+                })
+                .collect();
+            (component["name"].as_str().unwrap(), properties)
+        })
+        .collect();
+    assert_eq!(
+        components,
+        [
+            (
+                "Cargo.toml",
+                vec![
+                    ("up2date:ecosystem", "cargo"),
+                    ("up2date:directory", "."),
+                    ("up2date:covered", "true"),
+                ]
+            ),
+            (
+                "package.json",
+                vec![
+                    ("up2date:ecosystem", "npm"),
+                    ("up2date:directory", "."),
+                    ("up2date:covered", "false"),
+                ]
+            ),
+        ]
+    );
+    assert_eq!(actual["components"][0]["hashes"][0]["alg"], "SHA-1");
+}
+
+#[test]
+fn test_spdx_output_format() {
+    let temp_dir = create_incomplete_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.env("SOURCE_DATE_EPOCH", "1700000000");
+    cmd.args(["--format", "spdx"]);
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(actual["spdxVersion"], "SPDX-2.3");
+    assert_eq!(actual["creationInfo"]["created"], "2023-11-14T22:13:20Z");
+
+    let files = actual["files"].as_array().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0]["fileName"], "./Cargo.toml");
+    assert_eq!(
+        files[0]["annotations"][0]["comment"],
+        "ecosystem=cargo; directory=.; covered=false"
+    );
+    assert_eq!(
+        files[0]["checksums"][0]["checksumValue"]
+            .as_str()
+            .unwrap()
+            .len(),
+        40
+    );
+    assert_eq!(actual["relationships"].as_array().unwrap().len(), 2);
+
+    // The namespace is a URI whatever the directory is called.
+    let project = temp_dir.path().join("my project#1");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(project.join("Cargo.toml"), "").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(&project).args(["--format", "spdx"]);
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(actual["name"], "my project#1");
+    assert!(actual["documentNamespace"]
+        .as_str()
+        .unwrap()
+        .starts_with("https://github.com/black-desk/up2date/spdx/my%20project%231-"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_spdx_leaves_out_unreadable_manifests() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = create_incomplete_project();
+    let manifest = temp_dir.path().join("Cargo.toml");
+    fs::set_permissions(&manifest, fs::Permissions::from_mode(0o000)).unwrap();
+    if fs::read(&manifest).is_ok() {
+        // Running as root, which reads the file anyway.
+        fs::set_permissions(&manifest, fs::Permissions::from_mode(0o644)).unwrap();
+        return;
+    }
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).args(["--format", "spdx"]);
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    fs::set_permissions(&manifest, fs::Permissions::from_mode(0o644)).unwrap();
+
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let files = actual["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_ne!(files[0]["fileName"], "./Cargo.toml");
+    assert_eq!(
+        actual["comment"],
+        "Manifests that cannot be read are not listed: ./Cargo.toml"
+    );
+    assert_eq!(actual["relationships"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_template_output() {
+    let temp_dir = create_incomplete_project();
+
+    fs::write(
+        temp_dir.path().join("chat.txt.j2"),
+        "Dependabot covers {{ summary.configured_ecosystems }}/{{ summary.total_ecosystems }} \
+         ecosystems{% if missing_from_dependabot %}, missing: \
+         {{ missing_from_dependabot | sort | join(\", \") }}{% endif %}.\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--template", "chat.txt.j2"]);
+    cmd.assert()
+        .code(1)
+        .stdout("Dependabot covers 0/2 ecosystems, missing: cargo, npm.\n");
+
+    fs::write(temp_dir.path().join("broken.j2"), "{% if %}").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--template", "broken.j2"]);
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::starts_with(
+            "error: failed to render template broken.j2: ",
+        ));
+}
+
+#[test]
+fn test_multiple_outputs() {
+    let temp_dir = create_incomplete_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.env_remove("GITHUB_ACTIONS");
+    cmd.args([
+        "--output",
+        "json=report.json",
+        "--output",
+        "sarif=report.sarif",
+    ]);
+    cmd.assert().code(1).stdout(predicate::str::starts_with(
+        "# Dependabot Coverage Report\n",
+    ));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("report.json")).unwrap())
+            .unwrap();
+    assert_eq!(json["summary"]["missing_ecosystems"], 2);
+
+    let sarif: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("report.sarif")).unwrap())
+            .unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+}
+
+#[test]
+fn test_conflicting_formats_are_rejected() {
+    let temp_dir = create_incomplete_project();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--json", "--yaml"]);
+    cmd.assert().code(2).stdout(predicate::str::is_empty());
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--format", "toml", "--json"]);
+    cmd.assert().code(2);
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--output", "xml=report.xml"]);
+    cmd.assert().code(2).stderr(predicate::str::contains(
+        "unknown format `xml`, expected one of: markdown, json,",
+    ));
+}
+
+#[test]
+fn test_exclude_and_provider_options() {
+    let temp_dir = create_incomplete_project();
+
+    fs::write(
+        temp_dir.path().join("renovate.json"),
+        r#"{ "enabledManagers": ["cargo"] }"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args([
+        "--json",
+        "--provider",
+        "renovate",
+        "--exclude",
+        "package.json",
+    ]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let expected = serde_json::json!({
+        "summary": {
+            "total_ecosystems": 1,
+            "configured_ecosystems": 1,
+            "missing_ecosystems": 0
+        },
+        "project_dependencies": [
+            {
+                "ecosystem": "cargo",
+                "directory": "."
+            }
+        ],
+        "dependabot_ecosystems": ["cargo"],
+        "missing_from_dependabot": []
+    });
+    assert_json_eq_unordered(&actual, &expected);
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["--json", "--exclude", "["]);
+    cmd.assert().code(2).stderr(predicate::str::starts_with(
+        "error: invalid exclude pattern `[`",
+    ));
+}
+
+#[test]
+fn test_custom_detection_rules() {
+    let temp_dir = create_test_project();
+
+    fs::write(
+        temp_dir.path().join(".up2date.toml"),
+        r#"[[detect]]
+files = "**/BUILD.ourtool"
+content = 'go_repository\('
+ecosystem = "gomod"
+directory = "parent"
+
+[[detect]]
+files = "tools/*.ourtool"
+ecosystem = "ourtool"
+directory = "root"
+"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("services/api/build")).unwrap();
+    fs::write(
+        temp_dir.path().join("services/api/build/BUILD.ourtool"),
+        "go_repository(name = \"x\")\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("BUILD.ourtool"), "cc_library()\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join("tools")).unwrap();
+    fs::write(temp_dir.path().join("tools/lint.ourtool"), "").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).arg("--json");
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let mut missing: Vec<&str> = actual["missing_from_dependabot"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ecosystem| ecosystem.as_str().unwrap())
+        .collect();
+    missing.sort();
+    assert_eq!(missing, ["gomod", "ourtool"]);
+
+    let dependencies = actual["project_dependencies"].as_array().unwrap();
+    assert!(dependencies.contains(&serde_json::json!({
+        "ecosystem": "gomod",
+        "directory": "services/api"
+    })));
+    assert!(dependencies.contains(&serde_json::json!({
+        "ecosystem": "ourtool",
+        "directory": "."
+    })));
+
+    // No entry can cover an ecosystem Dependabot does not know, so the HTML
+    // fix and the conversion leave it out.
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).args(["--format", "html"]);
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let html = String::from_utf8(output).unwrap();
+    assert!(html.contains("package-ecosystem: gomod"));
+    assert!(!html.contains("package-ecosystem: ourtool"));
+    assert!(html.contains("Dependabot has no ecosystem named <code>ourtool</code>"));
+
+    fs::write(temp_dir.path().join("renovate.json"), "{}").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .args(["--json", "--provider", "renovate"]);
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let mut missing: Vec<&str> = actual["missing_from_dependabot"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ecosystem| ecosystem.as_str().unwrap())
+        .collect();
+    missing.sort();
+    // Renovate leaves git submodules alone unless they are enabled.
+    assert_eq!(missing, ["gitsubmodule", "ourtool"]);
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .args(["convert", "--to", "dependabot"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("package-ecosystem: gomod"))
+        .stdout(predicate::str::contains("ourtool").not())
+        .stderr(predicate::str::contains(
+            "renovate.json: `ourtool` dependencies were detected but Dependabot has no such \
+             ecosystem, so they get no entry",
+        ));
+}
+
+#[test]
+fn test_invalid_project_config() {
+    let temp_dir = create_test_project();
+
+    fs::write(
+        temp_dir.path().join(".up2date.toml"),
+        "[[detect]]\nfiles = \"**/BUILD\"\ncontent = \"(\"\necosystem = \"gomod\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path());
+    cmd.assert().failure().stderr(predicate::str::starts_with(
+        "error: .up2date.toml: detect[0].content: regex parse error",
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_unreadable_directory_is_reported() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = create_test_project();
+    let secret = temp_dir.path().join("secret");
+    fs::create_dir_all(&secret).unwrap();
+    fs::write(secret.join("package.json"), "{}").unwrap();
+    fs::set_permissions(&secret, fs::Permissions::from_mode(0o000)).unwrap();
+    if fs::read_dir(&secret).is_ok() {
+        // Running as root, which reads the directory anyway.
+        fs::set_permissions(&secret, fs::Permissions::from_mode(0o755)).unwrap();
+        return;
+    }
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).arg("--json");
+    let output = cmd.assert().code(0).get_output().stdout.clone();
+    fs::set_permissions(&secret, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let actual: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let findings = actual["findings"].as_array().unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0]["kind"], "unreadable-path");
+    assert_eq!(findings[0]["severity"], "warning");
+    assert_eq!(findings[0]["file"], "secret");
+}
+
+#[test]
+fn test_closed_stdout_is_not_an_error() {
+    let temp_dir = create_test_project();
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("up2date"))
+        .current_dir(temp_dir.path())
+        .args(["--format", "html"])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    drop(child.stdout.take());
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_fail_on_and_no_fail() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), "[package]\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: npm
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path()).arg("--json").args(args);
+        cmd.assert()
+    };
+
+    // The stale `npm` entry is only a warning by default.
+    run(&[]).code(0);
+    run(&["--fail-on", "stale"]).code(5);
+    run(&["--fail-on", "warning"]).code(6);
+    run(&["--fail-on", "missing,warning", "--fail-on", "stale"]).code(5);
+
+    fs::write(temp_dir.path().join("go.mod"), "module example.com/x\n").unwrap();
+    run(&[]).code(1);
+    run(&["--fail-on", "stale,missing"]).code(1);
+    run(&["--fail-on", "stale"]).code(5);
+    // A reached coverage does not make missing ecosystems fatal again.
+    run(&["--fail-on", "stale", "--min-coverage", "0"]).code(5);
+    run(&["--fail-on", "invalid", "--min-coverage", "0"]).code(0);
+    run(&["--fail-on", "stale", "--min-coverage", "100"]).code(1);
+    run(&["--fail-on", "invalid"]).code(0);
+    run(&["--no-fail"]).code(0);
+    run(&["--no-fail", "--fail-on", "missing"]).code(2);
+}
+
+#[test]
+fn test_min_coverage_and_ratchet() {
+    // cargo and gitsubmodule are configured, npm and gomod are not.
+    let temp_dir = create_incomplete_project();
+    fs::write(temp_dir.path().join(".gitmodules"), "").unwrap();
+    fs::write(temp_dir.path().join("go.mod"), "module example.com/x\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: gitsubmodule
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path()).arg("--json").args(args);
+        cmd.assert()
+    };
+
+    run(&["--min-coverage", "50"]).code(0);
+    run(&["--min-coverage", "50.01%"])
+        .code(1)
+        .stderr("coverage 50% is below the required 50.01%\n");
+    run(&["--min-coverage", "101"]).code(2);
+
+    let ratchet = temp_dir.path().join("coverage.toml");
+    run(&["--ratchet", "coverage.toml"]).code(0);
+    assert!(fs::read_to_string(&ratchet)
+        .unwrap()
+        .ends_with("coverage = 50.0\n"));
+    run(&["--ratchet", "coverage.toml", "--min-coverage", "60"]).code(1);
+
+    fs::remove_file(temp_dir.path().join("go.mod")).unwrap();
+    run(&["--ratchet", "coverage.toml"])
+        .code(0)
+        .stderr("coverage improved from 50% to 66.66%, updated coverage.toml\n");
+    assert!(fs::read_to_string(&ratchet)
+        .unwrap()
+        .ends_with("coverage = 66.66\n"));
+
+    fs::write(temp_dir.path().join("go.mod"), "module example.com/x\n").unwrap();
+    run(&["--ratchet", "coverage.toml"]).code(1);
+    run(&["--ratchet", "coverage.toml", "--no-fail"]).code(0);
+
+    fs::write(&ratchet, "coverage = 40\n").unwrap();
+    run(&["--ratchet", "coverage.toml"]).code(0);
+    assert!(fs::read_to_string(&ratchet)
+        .unwrap()
+        .ends_with("coverage = 50.0\n"));
+}
+
+#[test]
+fn test_baseline() {
+    // cargo is configured, npm is not.
+    let temp_dir = create_incomplete_project();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path()).args(args);
+        cmd.assert()
+    };
+
+    run(&["--baseline", "baseline.json"]).code(3);
+    run(&["--update-baseline"]).code(2);
+    run(&["--baseline", "baseline.json", "--update-baseline"])
+        .code(0)
+        .stderr("recorded 1 findings in baseline.json\n");
+
+    let baseline_path = temp_dir.path().join("baseline.json");
+    let mut baseline: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&baseline_path).unwrap()).unwrap();
+    assert_eq!(
+        baseline,
+        serde_json::json!({
+            "version": 1,
+            "entries": [{
+                "kind": "missing-ecosystem",
+                "ecosystem": "npm",
+                "directory": ".",
+            }],
+        })
+    );
+
+    baseline["entries"][0]["reason"] = "migrating to pnpm".into();
+    fs::write(&baseline_path, baseline.to_string()).unwrap();
+    let output = run(&["--baseline", "baseline.json", "--json"])
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        report["suppressed"],
+        serde_json::json!([{
+            "kind": "missing-ecosystem",
+            "severity": "error",
+            "file": "package.json",
+            "message": "`npm` dependencies in `.` are not updated by Dependabot",
+            "ecosystem": "npm",
+            "directory": ".",
+            "suppressed_by": "baseline.json",
+            "justification": "migrating to pnpm",
+        }])
+    );
+    run(&["--baseline", "baseline.json", "--format", "sarif"])
+        .code(0)
+        .stdout(predicate::str::contains(
+            r#""suppressions": [
+            {
+              "justification": "migrating to pnpm",
+              "kind": "external""#,
+        ));
+
+    // New findings still fail, and updating keeps the reason.
+    fs::write(temp_dir.path().join("go.mod"), "module example.com/x\n").unwrap();
+    run(&["--baseline", "baseline.json"]).code(1);
+    run(&["--baseline", "baseline.json", "--update-baseline"])
+        .code(0)
+        .stderr("recorded 2 findings in baseline.json\n");
+    let content = fs::read_to_string(&baseline_path).unwrap();
+    assert!(content.contains(r#""reason": "migrating to pnpm""#));
+    assert!(content.contains(r#""ecosystem": "gomod""#));
+
+    // Expired entries no longer suppress their findings.
+    fs::write(
+        &baseline_path,
+        content.replace(
+            r#""reason": "migrating to pnpm""#,
+            r#""expires": "2000-01-01", "reason": "migrating to pnpm""#,
+        ),
+    )
+    .unwrap();
+    run(&["--baseline", "baseline.json"]).code(1).stderr(
+        "warning: baseline entry for missing-ecosystem of npm in `.` expired on 2000-01-01\n",
+    );
+
+    fs::write(
+        &baseline_path,
+        r#"{"version": 1, "entries": [{"kind": "stale-entry", "expires": "soon"}]}"#,
+    )
+    .unwrap();
+    run(&["--baseline", "baseline.json"])
+        .code(3)
+        .stderr(predicate::str::contains("entries[0].expires"));
+}
+
+#[test]
+fn test_inline_suppressions() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("requirements.txt"),
+        "requests==2.32.0  # up2date: ignore pip -- pinned by the distribution\n",
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("tools")).unwrap();
+    fs::write(
+        temp_dir.path().join("tools/go.mod"),
+        "// up2date: ignore cargo\nmodule example.com/tools\n",
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"# up2date: ignore npm -- next to no entry
+version: 2
+updates:
+  # up2date: ignore npm -- the frontend is restored next month
+  - package-ecosystem: npm
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: npm
+    directory: /web
+    schedule:
+      interval: daily
+  - package-ecosystem: rust # up2date: ignore rust -- renamed in the next release
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).arg("--json");
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let suppressed: Vec<(&str, &str, &str)> = report["suppressed"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|finding| {
+            (
+                finding["kind"].as_str().unwrap(),
+                finding["suppressed_by"].as_str().unwrap(),
+                finding["justification"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        suppressed,
+        [
+            (
+                "missing-ecosystem",
+                "requirements.txt:1",
+                "pinned by the distribution"
+            ),
+            (
+                "unknown-ecosystem",
+                ".github/dependabot.yml:13",
+                "renamed in the next release"
+            ),
+            (
+                "stale-entry",
+                ".github/dependabot.yml:4",
+                "the frontend is restored next month"
+            ),
+        ]
+    );
+
+    // gomod is still missing, the comment only covers the npm entry below
+    // it, and the cargo comment and the one above `version` suppress nothing.
+    let findings: Vec<(&str, &str, u64)> = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|finding| {
+            (
+                finding["kind"].as_str().unwrap(),
+                finding["file"].as_str().unwrap(),
+                finding["line"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        findings,
+        [
+            ("stale-entry", ".github/dependabot.yml", 9),
+            ("unused-suppression", "tools/go.mod", 1),
+            ("unused-suppression", ".github/dependabot.yml", 1),
+        ]
+    );
+    let mut missing: Vec<&str> = report["missing_from_dependabot"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ecosystem| ecosystem.as_str().unwrap())
+        .collect();
+    missing.sort();
+    assert_eq!(missing, ["gomod", "pip"]);
+
+    // The suppressed pip dependencies are left out of the Markdown report.
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .env_remove("GITHUB_ACTIONS");
+    cmd.assert().code(1).stdout(predicate::str::contains(
+        "## Missing from Dependabot\n\n- gomod\n\n",
+    ));
+}
+
+#[test]
+fn test_inline_suppressions_match_the_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    // JSON has no comments, this is a string.
+    fs::write(
+        temp_dir.path().join("package.json"),
+        "{\"description\": \"# up2date: ignore npm\"}\n",
+    )
+    .unwrap();
+    // The lock file places gomod dependencies both in its own directory and
+    // in the parent, and the comment is about only one of them.
+    fs::create_dir_all(temp_dir.path().join("tools/build")).unwrap();
+    fs::write(
+        temp_dir.path().join("tools/build/deps.lock"),
+        "# up2date: ignore gomod\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join(".up2date.toml"),
+        r#"[[detect]]
+files = "**/deps.lock"
+ecosystem = "gomod"
+
+[[detect]]
+files = "**/deps.lock"
+ecosystem = "gomod"
+directory = "parent"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).arg("--json");
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    // Neither the npm dependencies nor the gomod ones in `tools` are
+    // suppressed, and the string in package.json is no unused suppression.
+    let suppressed = report["suppressed"].as_array().unwrap();
+    assert_eq!(suppressed.len(), 1);
+    assert_eq!(suppressed[0]["suppressed_by"], "tools/build/deps.lock:1");
+    assert!(report.get("findings").is_none());
+}
+
+#[test]
+fn test_diff_reports() {
+    // cargo is configured, npm is not.
+    let temp_dir = create_incomplete_project();
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    let write_config = |ecosystems: &[&str]| {
+        let mut config = "version: 2\nupdates:\n".to_string();
+        for ecosystem in ecosystems {
+            config.push_str(&format!(
+                "  - package-ecosystem: {}\n    directory: /\n    schedule:\n      interval: daily\n",
+                ecosystem
+            ));
+        }
+        fs::write(temp_dir.path().join(".github/dependabot.yml"), config).unwrap();
+    };
+    let save_report = |path: &str, format: &str| {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path())
+            .args(["--no-fail", "--output"])
+            .arg(format!("{}={}", format, path));
+        cmd.assert().success();
+    };
+
+    write_config(&["cargo"]);
+    save_report("old.json", "json");
+    write_config(&["npm", "pip"]);
+    save_report("new.yaml", "yaml");
+
+    let diff = |format: &str| {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path())
+            .args(["diff", "old.json", "new.yaml", "--format", format]);
+        cmd.assert()
+    };
+
+    let output = diff("json").success().get_output().stdout.clone();
+    let report_diff: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        report_diff["covered"],
+        serde_json::json!([{ "ecosystem": "npm", "directory": "." }])
+    );
+    assert_eq!(
+        report_diff["missing"],
+        serde_json::json!([{ "ecosystem": "cargo", "directory": "." }])
+    );
+    assert_eq!(report_diff["stale"][0]["ecosystem"], "pip");
+    assert_eq!(report_diff["summary"]["missing_ecosystems"], 0);
+    assert_eq!(report_diff["summary"]["coverage"], 0.0);
+
+    diff("markdown")
+        .success()
+        .stdout(predicate::str::contains(
+            "## Newly Covered\n\n- **npm** in `.`\n",
+        ))
+        .stdout(predicate::str::contains(
+            "## Newly Missing\n\n- **cargo** in `.`\n",
+        ));
+    diff("sarif")
+        .success()
+        .stdout(predicate::str::contains(r#""ruleId": "missing-ecosystem""#))
+        .stdout(predicate::str::contains(r#""ruleId": "stale-entry""#))
+        .stderr(
+            "warning: sarif only shows what got worse, leaving out newly covered: npm in `.`; \
+             1 of 2 ecosystems configured, 1 of 2 before; coverage changed by +0 percentage \
+             points\n",
+        );
+    diff("github").success().stdout(predicate::str::contains(
+        "::notice title=Coverage changes::newly covered: npm in `.`;",
+    ));
+    diff("html")
+        .success()
+        .stdout(predicate::str::contains(
+            "<h2>Newly Covered</h2>\n<ul>\n<li><strong>npm</strong> in <code>.</code></li>",
+        ))
+        .stdout(predicate::str::contains(
+            "<tr><td>Missing from dependabot</td><td>1</td><td>1</td><td>+0</td></tr>",
+        ));
+    diff("prometheus")
+        .success()
+        .stdout(predicate::str::contains(
+            "up2date_dependencies_newly_covered 1\n",
+        ))
+        .stdout(predicate::str::contains("up2date_coverage_change 0\n"));
+    diff("openmetrics")
+        .success()
+        .stdout(predicate::str::ends_with("# EOF\n"));
+    diff("spdx")
+        .code(2)
+        .stderr("error: the spdx format cannot show the differences between two reports\n");
+
+    // The format can also be chosen before the subcommand.
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .args(["--json", "diff", "old.json", "new.yaml"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let report_diff: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report_diff["summary"]["coverage"], 0.0);
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).args([
+        "--format", "sarif", "diff", "old.json", "new.yaml", "--format", "json",
+    ]);
+    cmd.assert()
+        .code(2)
+        .stderr("error: `--format sarif` before `diff` conflicts with `diff --format json`\n");
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).args([
+        "--template",
+        "report.j2",
+        "diff",
+        "old.json",
+        "new.yaml",
+    ]);
+    cmd.assert()
+        .code(2)
+        .stderr("error: `--template` cannot be used with `diff`\n");
+
+    // Options of the check would be silently ignored.
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path()).args([
+        "--output",
+        "json=x.json",
+        "diff",
+        "old.json",
+        "new.yaml",
+    ]);
+    cmd.assert()
+        .code(2)
+        .stderr("error: `--output` cannot be used with `diff`\n");
+    assert!(!temp_dir.path().join("x.json").exists());
+
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .args(["diff", "old.json", "missing.json"]);
+    cmd.assert().code(3);
+
+    // The same stale entry for another branch is new.
+    write_config(&["npm", "pip"]);
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        fs::read_to_string(temp_dir.path().join(".github/dependabot.yml")).unwrap()
+            + "  - package-ecosystem: pip\n    directory: /\n    target-branch: release\n    \
+               schedule:\n      interval: daily\n",
+    )
+    .unwrap();
+    save_report("release.json", "json");
+    let mut cmd = cargo_bin_cmd!("up2date");
+    cmd.current_dir(temp_dir.path())
+        .args(["diff", "new.yaml", "release.json", "--format", "json"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let report_diff: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let stale = report_diff["stale"].as_array().unwrap();
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0]["target_branch"], "release");
+}
+
+#[test]
+fn test_baseline_keeps_reporting_new_entries() {
+    let temp_dir = create_test_project();
+    let config_path = temp_dir.path().join(".github/dependabot.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}  - package-ecosystem: rust\n    directory: /\n    schedule:\n      interval: daily\n",
+            config
+        ),
+    )
+    .unwrap();
+
+    let run = || {
+        let mut cmd = cargo_bin_cmd!("up2date");
+        cmd.current_dir(temp_dir.path())
+            .args(["--json", "--baseline", "baseline.json"]);
+        cmd
+    };
+    run().arg("--update-baseline").assert().code(0);
+
+    // A second unknown ecosystem in the same file is a new finding.
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}  - package-ecosystem: pythn\n    directory: /\n    schedule:\n      interval: daily\n",
+            config
+        ),
+    )
+    .unwrap();
+    let output = run().assert().code(4).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let ecosystems = |key: &str| {
+        report[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|finding| finding["ecosystem"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ecosystems("findings"), ["pythn"]);
+    assert_eq!(ecosystems("suppressed"), ["rust"]);
+}
@@ -0,0 +1,490 @@
+// SPDX-FileCopyrightText: 2025 Chen Linxuan <me@black-desk.cn>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::fs;
+use tempfile::TempDir;
+use up2date::convert;
+use up2date::dependabot::DependabotConfig;
+use up2date::detect::{Detection, Detector, ProjectFile};
+use up2date::output::{self, OutputFormat};
+use up2date::renovate::RenovateConfig;
+use up2date::{
+    analyze_dependencies, Analyzer, DependencyReport, Ecosystem, Error, ProjectDependency, Provider,
+};
+
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+
+    fs::write(temp_dir.path().join("Cargo.toml"), "[package]\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join("vendor/lib")).unwrap();
+    fs::write(temp_dir.path().join("vendor/lib/package.json"), "{}\n").unwrap();
+
+    fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+    fs::write(
+        temp_dir.path().join(".github/dependabot.yml"),
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+"#,
+    )
+    .unwrap();
+
+    temp_dir
+}
+
+fn sorted(values: &[Ecosystem]) -> Vec<&str> {
+    let mut values: Vec<&str> = values.iter().map(Ecosystem::name).collect();
+    values.sort();
+    values
+}
+
+#[test]
+fn test_analyze_dependencies() {
+    let temp_dir = create_project();
+
+    let report = analyze_dependencies(temp_dir.path()).unwrap();
+
+    assert_eq!(sorted(&report.dependabot_ecosystems), ["cargo"]);
+    assert_eq!(sorted(&report.missing_from_dependabot), ["npm"]);
+    assert_eq!(report.summary.total_ecosystems, 2);
+    assert_eq!(report.summary.configured_ecosystems, 1);
+
+    let npm = report
+        .project_dependencies
+        .iter()
+        .find(|dep| dep.ecosystem == "npm")
+        .unwrap();
+    assert_eq!(npm.directory, "vendor/lib");
+    assert_eq!(npm.manifests, ["vendor/lib/package.json"]);
+}
+
+#[test]
+fn test_analyzer_excludes() {
+    let temp_dir = create_project();
+
+    let report = Analyzer::new(temp_dir.path())
+        .exclude("vendor")
+        .analyze()
+        .unwrap();
+
+    assert_eq!(report.summary.total_ecosystems, 1);
+    assert!(report.missing_from_dependabot.is_empty());
+}
+
+#[test]
+fn test_analyzer_renovate_provider() {
+    let temp_dir = create_project();
+    fs::remove_file(temp_dir.path().join(".github/dependabot.yml")).unwrap();
+    fs::write(
+        temp_dir.path().join("renovate.json"),
+        r#"{ "enabledManagers": ["npm"] }"#,
+    )
+    .unwrap();
+
+    let report = Analyzer::new(temp_dir.path())
+        .providers([Provider::Renovate])
+        .analyze()
+        .unwrap();
+
+    assert_eq!(sorted(&report.dependabot_ecosystems), ["npm"]);
+    assert_eq!(sorted(&report.missing_from_dependabot), ["cargo"]);
+    assert!(report.findings.is_empty());
+
+    let report = Analyzer::new(temp_dir.path())
+        .providers([Provider::Dependabot])
+        .analyze()
+        .unwrap();
+    assert_eq!(sorted(&report.missing_from_dependabot), ["cargo", "npm"]);
+}
+
+#[test]
+fn test_analyzer_errors() {
+    let temp_dir = create_project();
+
+    let err = Analyzer::new(temp_dir.path().join("Cargo.toml"))
+        .analyze()
+        .unwrap_err();
+    assert!(matches!(err, Error::NotADirectory(_)));
+
+    let err = Analyzer::new(temp_dir.path())
+        .exclude("vendor/[")
+        .analyze()
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidPattern { .. }));
+    assert!(err
+        .to_string()
+        .starts_with("invalid exclude pattern `vendor/[`"));
+}
+
+#[test]
+fn test_report_round_trips_through_json() {
+    let temp_dir = create_project();
+
+    let report = analyze_dependencies(temp_dir.path()).unwrap();
+    let json = output::render(OutputFormat::Json, &report, temp_dir.path()).unwrap();
+    let parsed: DependencyReport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.summary.missing_ecosystems, 1);
+    assert_eq!(
+        sorted(&parsed.missing_from_dependabot),
+        sorted(&report.missing_from_dependabot)
+    );
+}
+
+#[test]
+fn test_output_format_names() {
+    for format in OutputFormat::ALL {
+        assert_eq!(format.name().parse::<OutputFormat>().unwrap(), *format);
+    }
+
+    let err = "xml".parse::<OutputFormat>().unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("unknown format `xml`, expected one of: markdown, json,"));
+}
+
+#[test]
+fn test_ecosystem_names() {
+    for ecosystem in Ecosystem::ALL {
+        assert!(ecosystem.is_known());
+        assert_eq!(Ecosystem::from(ecosystem.name()), *ecosystem);
+        assert_eq!(
+            serde_json::to_string(&ecosystem).unwrap(),
+            format!("\"{}\"", ecosystem.name())
+        );
+    }
+
+    let ecosystem: Ecosystem = serde_json::from_str("\"yarn\"").unwrap();
+    assert_eq!(ecosystem, Ecosystem::Unknown("yarn".to_string()));
+    assert!(!ecosystem.is_known());
+    assert_eq!(serde_json::to_string(&ecosystem).unwrap(), "\"yarn\"");
+    assert_eq!(ecosystem.suggest(), Some(Ecosystem::Npm));
+
+    assert_eq!(Ecosystem::from_alias("Poetry"), Some(Ecosystem::Pip));
+    assert_eq!(Ecosystem::from_alias("cargo"), Some(Ecosystem::Cargo));
+    assert_eq!(Ecosystem::from_alias("nonsense"), None);
+    assert_eq!(Ecosystem::from("carg").suggest(), Some(Ecosystem::Cargo));
+
+    // Equality and hashing agree with the order, which compares names.
+    let unknown = Ecosystem::Unknown("npm".to_string());
+    assert_eq!(unknown.cmp(&Ecosystem::Npm), std::cmp::Ordering::Equal);
+    assert_eq!(unknown, Ecosystem::Npm);
+    let set: std::collections::HashSet<Ecosystem> = [unknown, Ecosystem::Npm].into();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_ecosystem_metadata() {
+    assert_eq!(
+        Ecosystem::from_manifest("Containerfile"),
+        Some(Ecosystem::Docker)
+    );
+    assert_eq!(Ecosystem::from_manifest("Gemfile"), None);
+    assert!(Ecosystem::Pip.is_detectable());
+    assert!(!Ecosystem::Maven.is_detectable());
+    assert!(Ecosystem::Gomod.supports_vendor());
+    assert!(!Ecosystem::Cargo.supports_vendor());
+    assert!(Ecosystem::DotnetSdk.is_beta());
+    assert!(!Ecosystem::Npm.is_beta());
+}
+
+struct BazelGoDetector;
+
+impl Detector for BazelGoDetector {
+    fn name(&self) -> &str {
+        "bazel-go"
+    }
+
+    fn detect(&self, file: &ProjectFile<'_>) -> Vec<Detection> {
+        if file.file_name() == "BUILD.bazel"
+            && file
+                .content()
+                .is_some_and(|content| content.contains("go_repository"))
+        {
+            vec![Detection::new(Ecosystem::Gomod, file)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[test]
+fn test_custom_detector() {
+    let temp_dir = create_project();
+    fs::create_dir_all(temp_dir.path().join("tools")).unwrap();
+    fs::write(
+        temp_dir.path().join("tools/BUILD.bazel"),
+        "go_repository(name = \"x\")\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("BUILD.bazel"), "cc_library()\n").unwrap();
+
+    let report = Analyzer::new(temp_dir.path())
+        .exclude("vendor")
+        .detector(BazelGoDetector)
+        .analyze()
+        .unwrap();
+
+    assert_eq!(sorted(&report.missing_from_dependabot), ["gomod"]);
+    let gomod: Vec<_> = report
+        .project_dependencies
+        .iter()
+        .filter(|dep| dep.ecosystem == Ecosystem::Gomod)
+        .collect();
+    assert_eq!(gomod.len(), 1);
+    assert_eq!(gomod[0].directory, "tools");
+    assert_eq!(gomod[0].manifests, ["tools/BUILD.bazel"]);
+}
+
+#[test]
+fn test_summary_coverage() {
+    let temp_dir = create_project();
+
+    let report = analyze_dependencies(temp_dir.path()).unwrap();
+    assert_eq!(report.summary.coverage(), 50.0);
+
+    fs::remove_dir_all(temp_dir.path().join("vendor")).unwrap();
+    fs::remove_file(temp_dir.path().join("Cargo.toml")).unwrap();
+    let report = analyze_dependencies(temp_dir.path()).unwrap();
+    assert_eq!(report.summary.total_ecosystems, 0);
+    assert_eq!(report.summary.coverage(), 100.0);
+}
+
+fn dependencies(ecosystems: &[(&str, &str)]) -> Vec<ProjectDependency> {
+    ecosystems
+        .iter()
+        .map(|(ecosystem, directory)| {
+            ProjectDependency::new(Ecosystem::from(*ecosystem), *directory, Vec::new())
+        })
+        .collect()
+}
+
+fn renovate_config(json: serde_json::Value) -> RenovateConfig {
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn test_renovate_disabled_managers() {
+    let config = renovate_config(serde_json::json!({
+        "npm": { "enabled": false },
+        "poetry": { "enabled": false }
+    }));
+    let conversion = convert::renovate_to_dependabot(
+        &config,
+        &dependencies(&[("cargo", "."), ("npm", "web"), ("pip", ".")]),
+    );
+
+    let ecosystems: Vec<&str> = conversion
+        .config
+        .updates
+        .iter()
+        .map(|update| update.package_ecosystem.name())
+        .collect();
+    assert_eq!(ecosystems, ["cargo", "pip"]);
+    assert_eq!(
+        conversion.warnings,
+        [
+            "disabling only the `poetry` manager has no Dependabot equivalent; \
+          `pip` is still updated"
+        ]
+    );
+
+    let temp_dir = create_project();
+    fs::remove_file(temp_dir.path().join(".github/dependabot.yml")).unwrap();
+    fs::write(
+        temp_dir.path().join("renovate.json"),
+        r#"{ "npm": { "enabled": false } }"#,
+    )
+    .unwrap();
+
+    let report = Analyzer::new(temp_dir.path())
+        .providers([Provider::Renovate])
+        .analyze()
+        .unwrap();
+    assert_eq!(sorted(&report.missing_from_dependabot), ["npm"]);
+}
+
+#[test]
+fn test_renovate_package_patterns() {
+    let config = renovate_config(serde_json::json!({
+        "packageRules": [{
+            "matchPackagePatterns": [
+                "^@angular/",
+                "/^eslint-plugin-.*$/",
+                "react$",
+                "^lodash\\.merge$",
+                "^(vue|nuxt)$",
+                "\\d"
+            ],
+            "groupName": "Frontend"
+        }]
+    }));
+    let conversion = convert::renovate_to_dependabot(&config, &dependencies(&[("npm", ".")]));
+
+    let group = &conversion.config.updates[0].groups["frontend"];
+    assert_eq!(
+        group.patterns,
+        ["@angular/*", "eslint-plugin-*", "*react", "lodash.merge"]
+    );
+    assert_eq!(
+        conversion.warnings,
+        [
+            "packageRules[0]: package pattern `^(vue|nuxt)$` cannot be expressed as a \
+             Dependabot wildcard",
+            "packageRules[0]: package pattern `\\d` cannot be expressed as a Dependabot wildcard",
+        ]
+    );
+}
+
+#[test]
+fn test_renovate_schedules() {
+    // The schedule, what it converts to, and whether it is approximated.
+    let cases = [
+        (
+            "* 3 * * 1",
+            serde_json::json!({"interval": "weekly", "day": "monday", "time": "03:00"}),
+            false,
+        ),
+        (
+            "* 5 1 * *",
+            serde_json::json!({"interval": "monthly", "time": "05:00"}),
+            false,
+        ),
+        (
+            "* 9 * * 1-5",
+            serde_json::json!({"interval": "daily", "time": "09:00"}),
+            true,
+        ),
+        (
+            "* 9 * 1,7 *",
+            serde_json::json!({"interval": "daily", "time": "09:00"}),
+            true,
+        ),
+        (
+            "before 12am on sunday",
+            serde_json::json!({"interval": "weekly", "day": "sunday", "time": "23:00"}),
+            false,
+        ),
+        (
+            "after 12am on monday",
+            serde_json::json!({"interval": "weekly", "day": "monday", "time": "00:00"}),
+            false,
+        ),
+        (
+            "after 12pm on friday",
+            serde_json::json!({"interval": "weekly", "day": "friday", "time": "12:00"}),
+            false,
+        ),
+        (
+            "after 10:30pm every weekday",
+            serde_json::json!({"interval": "daily", "time": "22:00"}),
+            true,
+        ),
+        ("after 13pm", serde_json::json!({"interval": "daily"}), true),
+        (
+            "at any time",
+            serde_json::json!({"interval": "daily"}),
+            false,
+        ),
+    ];
+
+    for (schedule, expected, approximated) in cases {
+        let config = renovate_config(serde_json::json!({ "schedule": schedule }));
+        let conversion = convert::renovate_to_dependabot(&config, &dependencies(&[("cargo", ".")]));
+
+        let actual = serde_json::to_value(&conversion.config.updates[0].schedule).unwrap();
+        assert_eq!(actual, expected, "{}", schedule);
+        assert_eq!(
+            !conversion.warnings.is_empty(),
+            approximated,
+            "{}",
+            schedule
+        );
+    }
+
+    let config = renovate_config(serde_json::json!({
+        "schedule": ["on monday", "on friday"]
+    }));
+    let conversion = convert::renovate_to_dependabot(&config, &dependencies(&[("cargo", ".")]));
+    assert_eq!(
+        conversion.warnings,
+        ["Dependabot supports a single schedule; only `on monday` was converted"]
+    );
+}
+
+#[test]
+fn test_dependabot_schedules_and_labels_per_manager() {
+    let config: DependabotConfig = serde_yaml::from_str(
+        r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: weekly
+      day: friday
+    labels: [rust]
+  - package-ecosystem: npm
+    directory: /
+    schedule:
+      interval: daily
+      time: "05:00"
+    labels: [javascript, frontend]
+"#,
+    )
+    .unwrap();
+
+    let conversion = convert::dependabot_to_renovate(&config);
+    assert!(conversion.warnings.is_empty());
+
+    let actual = serde_json::to_value(&conversion.config).unwrap();
+    assert_eq!(
+        actual,
+        serde_json::json!({
+            "$schema": "https://docs.renovatebot.com/renovate-schema.json",
+            "enabledManagers": ["cargo", "npm"],
+            "packageRules": [
+                { "matchManagers": ["cargo"], "schedule": ["* * * * 5"] },
+                { "matchManagers": ["npm"], "schedule": ["* 5 * * *"] },
+                { "matchManagers": ["cargo"], "labels": ["rust"] },
+                { "matchManagers": ["npm"], "labels": ["javascript", "frontend"] }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_dependabot_round_trips_through_renovate() {
+    let yaml = r#"version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: weekly
+      day: monday
+      time: "04:00"
+    target-branch: develop
+    ignore:
+      - dependency-name: serde
+        update-types: ["version-update:semver-major"]
+    groups:
+      tokio:
+        patterns: ["tokio*"]
+        exclude-patterns: [tokio-test]
+"#;
+    let config: DependabotConfig = serde_yaml::from_str(yaml).unwrap();
+
+    let renovate = convert::dependabot_to_renovate(&config);
+    assert!(renovate.warnings.is_empty());
+    let dependabot =
+        convert::renovate_to_dependabot(&renovate.config, &dependencies(&[("cargo", ".")]));
+    assert!(dependabot.warnings.is_empty());
+
+    assert_eq!(
+        serde_json::to_value(&dependabot.config).unwrap(),
+        serde_json::to_value(&config).unwrap()
+    );
+}